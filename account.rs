@@ -0,0 +1,15 @@
+use crate::hash::stable_hash;
+
+/// Whose puzzle inputs are being worked with. Inputs differ between accounts, so each gets its own corner of the cache.
+pub struct Account {
+	pub name: String,
+	/// Only needed for requests to Advent of Code, so it can be missing when working from the cache
+	pub session: Option<String>,
+}
+
+impl Account {
+	/// A stable identity derived from the session, so the cache never mixes up inputs from different accounts
+	pub fn id(&self) -> Option<String> {
+		Some(stable_hash(self.session.as_ref()?.trim()))
+	}
+}
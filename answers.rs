@@ -0,0 +1,74 @@
+use std::{collections::BTreeMap, error::Error, path::PathBuf};
+
+use advent_of_code_2023::Answer;
+use serde::{Deserialize, Serialize};
+use tokio::fs::{read_to_string, write};
+
+use crate::cache::InputsCache;
+
+/// The accepted answers for each day, kept in `answers.toml` beside the account's cached inputs. Correct submissions are
+/// recorded automatically, and the file can be edited by hand.
+pub struct AnswerBook {
+	path: PathBuf,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct DayAnswers {
+	pub part_1: Option<String>,
+	pub part_2: Option<String>,
+}
+
+impl DayAnswers {
+	pub fn part(&self, part: u8) -> Option<&String> {
+		match part {
+			1 => self.part_1.as_ref(),
+			2 => self.part_2.as_ref(),
+			_ => panic!("Invalid part: {part}"),
+		}
+	}
+}
+
+impl AnswerBook {
+	pub fn new(cache: &InputsCache) -> AnswerBook {
+		AnswerBook {
+			path: cache.path().join("answers.toml"),
+		}
+	}
+
+	// Days are keyed as `day_{day}`, since TOML keys have to be strings
+	async fn load(&self) -> Result<BTreeMap<String, DayAnswers>, String> {
+		match read_to_string(&self.path).await {
+			Ok(text) => toml::from_str(&text).map_err(|error| format!("Invalid answers at {}: {error}", self.path.display())),
+			Err(_) => Ok(BTreeMap::new()),
+		}
+	}
+
+	/// Every day with at least one recorded answer, in order
+	pub async fn days(&self) -> Result<Vec<(u64, DayAnswers)>, String> {
+		let mut days = self
+			.load()
+			.await?
+			.into_iter()
+			.filter_map(|(key, answers)| Some((key.strip_prefix("day_")?.parse::<u64>().ok()?, answers)))
+			.collect::<Vec<_>>();
+
+		days.sort_by_key(|(day, _)| *day);
+
+		Ok(days)
+	}
+
+	pub async fn record(&self, day: u64, part: u8, answer: &Answer) -> Result<(), Box<dyn Error>> {
+		let mut answers = self.load().await?;
+		let entry = answers.entry(format!("day_{day}")).or_default();
+
+		match part {
+			1 => entry.part_1 = Some(answer.to_string()),
+			2 => entry.part_2 = Some(answer.to_string()),
+			_ => panic!("Invalid part: {part}"),
+		}
+
+		write(&self.path, toml::to_string(&answers)?).await?;
+
+		Ok(())
+	}
+}
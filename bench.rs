@@ -0,0 +1,96 @@
+use std::{
+	hint::black_box,
+	time::{Duration, Instant},
+};
+
+use crate::solution::Solution;
+
+/// How long each run of the same piece of code took
+pub struct Samples(Vec<Duration>);
+
+/// Samples of parsing a day's input and solving each of its parts
+pub struct Measurements {
+	pub parse: Samples,
+	pub part_one: Samples,
+	pub part_two: Samples,
+}
+
+/// Times parsing and each part separately, `iterations` times, after running them `warmup` times without timing. Cloning the
+/// input for each run isn't included.
+pub fn measure<S: Solution>(input: String, warmup: u32, iterations: u32) -> Measurements {
+	for _ in 0..warmup {
+		let solution = black_box(S::parse(input.clone()));
+		black_box(solution.part_one());
+		black_box(solution.part_two());
+	}
+
+	let mut parse = Vec::new();
+	let mut part_one = Vec::new();
+	let mut part_two = Vec::new();
+
+	for _ in 0..iterations {
+		let input = input.clone();
+
+		let start = Instant::now();
+		let solution = black_box(S::parse(input));
+		parse.push(start.elapsed());
+
+		let start = Instant::now();
+		black_box(solution.part_one());
+		part_one.push(start.elapsed());
+
+		let start = Instant::now();
+		black_box(solution.part_two());
+		part_two.push(start.elapsed());
+	}
+
+	Measurements {
+		parse: Samples(parse),
+		part_one: Samples(part_one),
+		part_two: Samples(part_two),
+	}
+}
+
+impl Samples {
+	fn nanos(&self) -> Vec<f64> {
+		self.0.iter().map(|duration| duration.as_nanos() as f64).collect()
+	}
+
+	#[allow(clippy::len_without_is_empty)]
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	pub fn min(&self) -> f64 {
+		self.nanos().into_iter().fold(f64::INFINITY, f64::min)
+	}
+
+	pub fn median(&self) -> f64 {
+		let mut nanos = self.nanos();
+		nanos.sort_by(f64::total_cmp);
+
+		let middle = nanos.len() / 2;
+
+		if nanos.len().is_multiple_of(2) {
+			(nanos[middle - 1] + nanos[middle]) / 2.0
+		} else {
+			nanos[middle]
+		}
+	}
+
+	pub fn mean(&self) -> f64 {
+		self.nanos().iter().sum::<f64>() / self.0.len() as f64
+	}
+
+	/// The sample standard deviation
+	pub fn stddev(&self) -> f64 {
+		if self.0.len() < 2 {
+			return 0.0;
+		}
+
+		let mean = self.mean();
+		let variance = self.nanos().iter().map(|nanos| (nanos - mean).powi(2)).sum::<f64>() / (self.0.len() - 1) as f64;
+
+		variance.sqrt()
+	}
+}
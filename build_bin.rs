@@ -1,109 +1,115 @@
-use std::time::Instant;
+use std::{
+	error::Error,
+	path::{Path, PathBuf},
+	time::Instant,
+};
 
-use regex::Regex;
+use advent_of_code_2023::find_day;
 use tokio::{
-	fs::{read_to_string, write},
+	fs::{copy, create_dir_all, try_exists, write},
 	process::Command,
 };
 
-use crate::utils::into_lines;
+/// Builds `target/day_{day}_bin`, an optimized binary with the input embedded, then runs it
+pub async fn build_bin(day: u64, input: String) -> Result<(), Box<dyn Error>> {
+	if find_day(day).is_none() {
+		return Err(format!("Unknown day {day}").into());
+	}
 
-pub async fn build_bin(day: u64, input: String) {
-	let fn_name = get_fn_name(day).await;
+	let main = format!(
+		"use advent_of_code_2023::find_day;
 
-	new_project(day).await;
-	write_main_file(&fn_name, input).await;
-	build(day).await;
-	cleanup().await;
-	execute(day).await;
-}
+const INPUT: &str = include_str!(\"day_{day}.txt\");
 
-async fn get_fn_name(day: u64) -> String {
-	let this_file = read_to_string("main.rs").await.unwrap();
-	let regex = Regex::new(r"^\s*(\d+)\s*=>\s*(\w+)\(input\)\s*,\s*$").unwrap();
-
-	let fn_name = into_lines(this_file).iter().find_map(|line| match regex.captures(&line) {
-		Some(capture) => {
-			if capture.get(1).unwrap().as_str() == &day.to_string() {
-				Some(capture.get(2).unwrap().as_str().to_owned())
-			} else {
-				None
-			}
-		}
-		None => None,
-	});
-
-	if fn_name.is_none() {
-		panic!("Couldn't find a function name for day {day}")
-	}
+fn main() {{
+	let [part_one, part_two] = (find_day({day}).unwrap().solve)(INPUT.to_owned()).answers;
+	println!(\"part_one={{part_one}} part_two={{part_two}}\");
+}}
+"
+	);
 
-	fn_name.unwrap()
+	let binary = build_project(&format!("day_{day}_bin"), &main, &[(format!("day_{day}.txt"), input)]).await?;
+	execute(&binary).await
 }
 
-async fn new_project(day: u64) {
-	Command::new("cargo")
-		.args(&["new", &format!("day_{day}_bin")])
-		.spawn()
-		.unwrap()
-		.wait()
-		.await
-		.unwrap();
-	Command::new("mv")
-		.args(&["day_5_bin", "build"])
-		.spawn()
-		.unwrap()
-		.wait()
-		.await
-		.unwrap();
-	Command::new("cargo")
-		.args(&["add", "--path", "../"])
-		.current_dir("build")
-		.spawn()
-		.unwrap()
-		.wait()
-		.await
-		.unwrap();
-}
+/// Builds `target/all_days_bin`, one optimized binary with every given input embedded, then runs it. It runs each day and
+/// prints a summary table.
+pub async fn build_bundle(inputs: Vec<(u64, String)>) -> Result<(), Box<dyn Error>> {
+	let entries = inputs
+		.iter()
+		.map(|(day, _)| format!("\t({day}, include_str!(\"day_{day}.txt\")),\n"))
+		.collect::<String>();
 
-async fn write_main_file(fn_name: &str, input: String) {
-	let import = format!("use advent_of_code_2023::{{{fn_name}}};");
-	let input_code = format!("let input = \"{input}\".to_owned();");
-	let call = format!("{fn_name}(input);");
-	let main = format!("{import}\n\nfn main() {{\n\t{input_code}\n\t{call}\n}}\n");
+	let main = format!("const INPUTS: &[(u64, &str)] = &[\n{entries}];\n\nfn main() {{\n\tadvent_of_code_2023::run_bundle(INPUTS);\n}}\n");
+	let files = inputs.into_iter().map(|(day, input)| (format!("day_{day}.txt"), input)).collect::<Vec<_>>();
 
-	write("build/src/main.rs", main).await.unwrap();
+	let binary = build_project("all_days_bin", &main, &files).await?;
+	execute(&binary).await
 }
 
-async fn build(day: u64) {
-	Command::new("cargo")
-		.args(&["build", "--release"])
-		.current_dir("build")
-		.spawn()
-		.unwrap()
-		.wait()
-		.await
-		.unwrap();
-
-	Command::new("mv")
-		.args(&[format!("build/target/release/day_{day}_bin"), format!("target/day_{day}_bin")])
-		.spawn()
-		.unwrap()
-		.wait()
-		.await
-		.unwrap();
-}
+/// Builds a project in a temporary directory, with `main` as its `main.rs` and `files` beside it, and copies the release
+/// binary into `target`. The temporary directory is removed whether or not the build succeeds.
+pub async fn build_project(name: &str, main: &str, files: &[(String, String)]) -> Result<PathBuf, Box<dyn Error>> {
+	// A fresh directory that nobody else can have prepared, since cargo would run a build.rs that it found there
+	let directory = tempfile::Builder::new().prefix(&format!("{name}_")).tempdir()?;
 
-async fn cleanup() {
-	Command::new("rm").args(&["-rf", "build"]).spawn().unwrap().wait().await.unwrap();
+	build_in(directory.path(), name, main, files).await
 }
 
-async fn execute(day: u64) {
-	let file = format!("target/day_{day}_bin");
-	let mut child = Command::new(&file).spawn().unwrap();
+async fn build_in(directory: &Path, name: &str, main: &str, files: &[(String, String)]) -> Result<PathBuf, Box<dyn Error>> {
+	let repository = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+	let target = repository.join("target");
+	let source = directory.join("src");
 
-	let start = Instant::now();
-	child.wait().await.unwrap();
+	create_dir_all(&source).await?;
+
+	// The path is written as a TOML string, so that it's escaped properly
+	let dependency = toml::Value::String(repository.to_string_lossy().into_owned());
+	let manifest = format!(
+		"[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\nadvent_of_code_2023 = {{ path = {dependency} }}\n\n[workspace]\n"
+	);
+
+	write(directory.join("Cargo.toml"), manifest).await?;
+	write(source.join("main.rs"), main).await?;
 
+	for (file, contents) in files {
+		write(source.join(file), contents).await?;
+	}
+
+	// Starting from the repository's lockfile keeps the dependencies at the versions that are known to work
+	if try_exists(repository.join("Cargo.lock")).await? {
+		copy(repository.join("Cargo.lock"), directory.join("Cargo.lock")).await?;
+	}
+
+	// Sharing a target directory between builds means the dependencies are only compiled once
+	let build_target = target.join("build_bin");
+	let status = Command::new("cargo")
+		.args(["build", "--release", "--target-dir"])
+		.arg(&build_target)
+		.current_dir(directory)
+		.status()
+		.await?;
+
+	if !status.success() {
+		return Err(format!("Building {name} failed with {status}").into());
+	}
+
+	let binary = target.join(name);
+	copy(build_target.join("release").join(name), &binary).await?;
+
+	Ok(binary)
+}
+
+async fn execute(binary: &Path) -> Result<(), Box<dyn Error>> {
+	let start = Instant::now();
+	let status = Command::new(binary).status().await?;
 	let time = start.elapsed().as_millis();
-	println!("Executed {file} in {time}ms");
+
+	if !status.success() {
+		return Err(format!("{} failed with {status}", binary.display()).into());
+	}
+
+	println!("Executed {} in {time}ms", binary.display());
+
+	Ok(())
 }
@@ -1,21 +1,60 @@
-use std::{env, path::PathBuf};
+use std::{
+    env,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
 
-use tokio::fs::{create_dir, metadata, read_to_string, write};
+use regex::Regex;
+use tokio::fs::{
+    create_dir_all, read_dir, read_to_string, remove_dir, remove_file, rename, try_exists, write,
+};
+
+use crate::account::Account;
 
 pub struct InputsCache {
+    root: PathBuf,
     path: PathBuf,
 }
 
 impl InputsCache {
-    pub async fn new() -> InputsCache {
-        let home = env::var("HOME").unwrap();
-        let path = PathBuf::from(home).join(".cache/advent_of_code_2023");
+    /// Uses `directory` if it's given, otherwise `$XDG_CACHE_HOME/advent_of_code_{year}`, falling back to `~/.cache`.
+    /// Each account's inputs, pages and guesses live in their own subdirectory, named by the account's id.
+    pub async fn new(
+        year: u64,
+        directory: Option<PathBuf>,
+        account: &Account,
+    ) -> io::Result<InputsCache> {
+        let root = match directory {
+            Some(directory) => directory,
+            None => default_cache_home()?.join(format!("advent_of_code_{year}")),
+        };
+
+        create_dir_all(&root).await?;
 
-        if !metadata(path.clone()).await.is_ok_and(|meta| meta.is_dir()) {
-            create_dir(path.clone()).await.unwrap()
+        if account.name.is_empty()
+            || account.name.contains(['/', '\\'])
+            || account.name.starts_with('.')
+        {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("{:?} can't be used as an account name", account.name),
+            ));
         }
 
-        InputsCache { path }
+        let path = root.join(account_id(&root, account).await?);
+        create_dir_all(&path).await?;
+
+        Ok(InputsCache { root, path })
+    }
+
+    /// The directory shared by every account
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The current account's directory
+    pub fn path(&self) -> &Path {
+        &self.path
     }
 
     pub async fn get(&self, day: u64) -> Option<String> {
@@ -24,9 +63,293 @@ impl InputsCache {
             .ok()
     }
 
-    pub async fn set(&self, day: u64, text: String) {
-        write(self.path.join(format!("day_{day}.txt")), text)
+    pub async fn set(&self, day: u64, text: String) -> io::Result<()> {
+        write(self.path.join(format!("day_{day}.txt")), text).await
+    }
+
+    pub async fn get_page(&self, day: u64) -> Option<String> {
+        read_to_string(self.path.join(format!("day_{day}.html")))
             .await
-            .unwrap()
+            .ok()
+    }
+
+    pub async fn set_page(&self, day: u64, html: String) -> io::Result<()> {
+        write(self.path.join(format!("day_{day}.html")), html).await
+    }
+
+    /// The days that have a cached input, in order
+    pub async fn days(&self) -> Vec<u64> {
+        cached_days(&self.path, "txt").await.unwrap_or_default()
+    }
+
+    /// Removes the input and puzzle page for a day. Returns false if there was no input to remove.
+    pub async fn remove(&self, day: u64) -> bool {
+        let _ = remove_file(self.path.join(format!("day_{day}.html"))).await;
+
+        remove_file(self.path.join(format!("day_{day}.txt")))
+            .await
+            .is_ok()
+    }
+
+    /// Removes every input and puzzle page, including pages of days without an input, but keeps the record of submitted
+    /// guesses. Returns the days whose input was removed.
+    pub async fn clear(&self) -> Vec<u64> {
+        let days = self.days().await;
+
+        for day in &days {
+            self.remove(*day).await;
+        }
+
+        for day in cached_days(&self.path, "html").await.unwrap_or_default() {
+            let _ = remove_file(self.path.join(format!("day_{day}.html"))).await;
+        }
+
+        days
+    }
+
+    /// Records which Advent of Code user the account's session belongs to, and carries over the caches of that user's earlier
+    /// sessions, which no account uses any more. Returns how many were carried over.
+    pub async fn confirm_user(&self, user: &str) -> io::Result<usize> {
+        write(self.path.join("user.txt"), user).await?;
+
+        let linked = linked_accounts(&self.root).await;
+        let mut entries = read_dir(&self.root).await?;
+        let mut carried = 0;
+
+        while let Some(entry) = entries.next_entry().await? {
+            let directory = entry.path();
+            let id = entry.file_name().to_string_lossy().to_string();
+
+            if directory == self.path || linked.iter().any(|(_, linked)| *linked == id) {
+                continue;
+            }
+
+            match read_to_string(directory.join("user.txt")).await {
+                Ok(recorded) if recorded == user => {}
+                _ => continue,
+            }
+
+            // Whatever the current session has cached already is kept, and anything left behind stays where it is
+            let mut files = read_dir(&directory).await?;
+
+            while let Some(file) = files.next_entry().await? {
+                let target = self.path.join(file.file_name());
+
+                if !try_exists(&target).await? {
+                    rename(file.path(), target).await?;
+                }
+            }
+
+            let _ = remove_file(directory.join("user.txt")).await;
+            let _ = remove_dir(&directory).await;
+            carried += 1;
+        }
+
+        Ok(carried)
+    }
+
+    /// Copies every cached input into `directory`, as `day_{day}.txt`
+    pub async fn export(&self, directory: &Path) -> io::Result<Vec<u64>> {
+        let days = self.days().await;
+
+        create_dir_all(directory).await?;
+
+        for day in &days {
+            let input = self.get(*day).await.unwrap();
+            write(directory.join(format!("day_{day}.txt")), input).await?;
+        }
+
+        Ok(days)
+    }
+
+    /// Caches every `day_{day}.txt` in `directory`, replacing whatever was cached for those days
+    pub async fn import(&self, directory: &Path) -> io::Result<Vec<u64>> {
+        let days = cached_days(directory, "txt").await?;
+
+        for day in &days {
+            let input = read_to_string(directory.join(format!("day_{day}.txt"))).await?;
+            self.set(*day, input).await?;
+        }
+
+        Ok(days)
+    }
+}
+
+// The id of each account is remembered by name, so that its inputs can still be found when no session is available.
+// An account that has never had a session is cached under its name.
+async fn account_id(root: &Path, account: &Account) -> io::Result<String> {
+    let mut known = linked_accounts(root).await;
+
+    let remembered = known
+        .iter()
+        .find(|(name, _)| name == &account.name)
+        .map(|(_, id)| id.clone());
+
+    let id = match account.id() {
+        Some(id) => id,
+        None => return Ok(remembered.unwrap_or(account.name.clone())),
+    };
+
+    if remembered.as_ref() == Some(&id) {
+        return Ok(id);
+    }
+
+    if let Some(previous) = remembered {
+        if try_exists(root.join(previous)).await? {
+            eprintln!(
+                "The session for the {} account has changed, so it starts with an empty cache. If it's the same user, `.run auth check` carries the old cache over, as long as the old session was checked too.",
+                account.name
+            );
+        }
+    }
+
+    known.retain(|(name, _)| name != &account.name);
+    known.push((account.name.clone(), id.clone()));
+
+    let lines = known
+        .iter()
+        .map(|(name, id)| format!("{name} {id}\n"))
+        .collect::<String>();
+
+    write(root.join("accounts.txt"), lines).await?;
+
+    Ok(id)
+}
+
+async fn linked_accounts(root: &Path) -> Vec<(String, String)> {
+    let text = read_to_string(root.join("accounts.txt"))
+        .await
+        .unwrap_or_default();
+
+    text.lines()
+        .filter_map(|line| line.rsplit_once(' '))
+        .map(|(name, id)| (name.to_owned(), id.to_owned()))
+        .collect()
+}
+
+fn default_cache_home() -> io::Result<PathBuf> {
+    if let Ok(directory) = env::var("XDG_CACHE_HOME") {
+        return Ok(PathBuf::from(directory));
+    }
+
+    match env::var("HOME") {
+        Ok(home) => Ok(PathBuf::from(home).join(".cache")),
+        Err(_) => Err(io::Error::new(
+            ErrorKind::NotFound,
+            "neither XDG_CACHE_HOME nor HOME is set, so pass --cache-dir",
+        )),
+    }
+}
+
+async fn cached_days(directory: &Path, extension: &str) -> io::Result<Vec<u64>> {
+    let regex = Regex::new(&format!(r"^day_(\d+)\.{extension}$")).unwrap();
+    let mut entries = read_dir(directory).await?;
+    let mut days = Vec::new();
+
+    while let Some(entry) = entries.next_entry().await? {
+        if let Some(captures) = regex.captures(&entry.file_name().to_string_lossy()) {
+            days.push(captures.get(1).unwrap().as_str().parse::<u64>().unwrap());
+        }
+    }
+
+    days.sort();
+
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(session: &str) -> Account {
+        Account {
+            name: "default".to_owned(),
+            session: Some(session.to_owned()),
+        }
+    }
+
+    #[tokio::test]
+    async fn keeps_each_session_apart() {
+        let directory = tempfile::tempdir().unwrap();
+        let root = Some(directory.path().to_owned());
+
+        let mine = InputsCache::new(2023, root.clone(), &account("mine"))
+            .await
+            .unwrap();
+        mine.set(1, "mine".to_owned()).await.unwrap();
+
+        let theirs = InputsCache::new(2023, root.clone(), &account("theirs"))
+            .await
+            .unwrap();
+        assert_ne!(mine.path(), theirs.path());
+        assert_eq!(theirs.get(1).await, None);
+
+        let offline = InputsCache::new(
+            2023,
+            root,
+            &Account {
+                name: "default".to_owned(),
+                session: None,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(offline.path(), theirs.path());
+    }
+
+    #[tokio::test]
+    async fn carries_over_an_earlier_session_of_the_same_user() {
+        let directory = tempfile::tempdir().unwrap();
+        let root = Some(directory.path().to_owned());
+
+        let old = InputsCache::new(2023, root.clone(), &account("old"))
+            .await
+            .unwrap();
+        old.set(1, "input".to_owned()).await.unwrap();
+        old.confirm_user("alice").await.unwrap();
+
+        let new = InputsCache::new(2023, root.clone(), &account("new"))
+            .await
+            .unwrap();
+        assert_eq!(new.get(1).await, None);
+        assert_eq!(new.confirm_user("bob").await.unwrap(), 0);
+        assert_eq!(new.get(1).await, None);
+
+        let new = InputsCache::new(2023, root, &account("newer"))
+            .await
+            .unwrap();
+        assert_eq!(new.confirm_user("alice").await.unwrap(), 1);
+        assert_eq!(new.get(1).await.as_deref(), Some("input"));
+        assert!(!old.path().exists());
+    }
+
+    #[tokio::test]
+    async fn clears_pages_without_an_input() {
+        let directory = tempfile::tempdir().unwrap();
+        let cache = InputsCache::new(2023, Some(directory.path().to_owned()), &account("mine"))
+            .await
+            .unwrap();
+
+        cache.set(1, "input".to_owned()).await.unwrap();
+        cache.set_page(1, "<html></html>".to_owned()).await.unwrap();
+        cache.set_page(2, "<html></html>".to_owned()).await.unwrap();
+
+        assert_eq!(cache.clear().await, vec![1]);
+        assert_eq!(cache.get_page(1).await, None);
+        assert_eq!(cache.get_page(2).await, None);
+    }
+
+    #[tokio::test]
+    async fn reports_an_input_that_cant_be_written() {
+        let directory = tempfile::tempdir().unwrap();
+        let cache = InputsCache::new(2023, Some(directory.path().to_owned()), &account("mine"))
+            .await
+            .unwrap();
+
+        create_dir_all(cache.path().join("day_1.txt"))
+            .await
+            .unwrap();
+
+        assert!(cache.set(1, "input".to_owned()).await.is_err());
     }
 }
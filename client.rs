@@ -0,0 +1,304 @@
+use std::{
+	error::Error,
+	fmt::{self, Display},
+	io,
+	time::Duration,
+};
+
+use advent_of_code_2023::Answer;
+use regex::Regex;
+use reqwest::{redirect, Client, Method, RequestBuilder, StatusCode};
+
+use crate::{puzzle::decode_entities, submit::Verdict, throttle::Throttle};
+
+pub struct AdventClient {
+	client: Client,
+	host: String,
+	base_url: String,
+	session: Option<String>,
+	throttle: Throttle,
+}
+
+/// Why a request to Advent of Code didn't produce what was asked for
+#[derive(Debug)]
+pub enum FetchError {
+	/// There is no session to authenticate with, so no request was made
+	NoSession,
+	Network(reqwest::Error),
+	/// The server didn't recognise the session, and served the logged-out version of the page
+	LoggedOut,
+	/// A 400 response, which is what an expired or malformed session cookie gets
+	BadSession,
+	/// A 404 response, which is what a puzzle gets before it unlocks
+	NotFound,
+	Status(StatusCode),
+	/// The request came too soon after the previous one, and the throttle is set to refuse rather than delay
+	Throttled(Duration),
+	/// The time of the request couldn't be recorded for the throttle, so no request was made
+	Throttle(io::Error),
+	/// What was fetched couldn't be written to the cache
+	Cache(io::Error),
+	/// A successful response whose body doesn't look like a puzzle input
+	UnexpectedBody,
+}
+
+impl AdventClient {
+	/// The base URL can point at a stand-in for adventofcode.com, such as a local mock server
+	pub fn new(base_url: &str, year: u64, session: Option<String>, user_agent: &str, throttle: Throttle) -> AdventClient {
+		let host = base_url.trim_end_matches('/').to_owned();
+
+		// Redirects aren't followed, since an expired session is redirected to the login page rather than refused
+		AdventClient {
+			client: Client::builder()
+				.user_agent(user_agent)
+				.redirect(redirect::Policy::none())
+				.build()
+				.unwrap(),
+			base_url: format!("{host}/{year}"),
+			host,
+			session,
+			throttle,
+		}
+	}
+
+	fn request(&self, method: Method, path: &str) -> RequestBuilder {
+		self.client.request(method, format!("{}{path}", self.base_url))
+	}
+
+	async fn send(&self, request: RequestBuilder) -> Result<String, FetchError> {
+		let session = self.session.as_ref().ok_or(FetchError::NoSession)?;
+
+		self.throttle.acquire().await?;
+
+		let response = request.header("Cookie", format!("session={session}")).send().await?;
+
+		match response.status() {
+			StatusCode::OK => Ok(response.text().await?),
+			StatusCode::BAD_REQUEST => Err(FetchError::BadSession),
+			StatusCode::NOT_FOUND => Err(FetchError::NotFound),
+			status if status.is_redirection() => Err(FetchError::LoggedOut),
+			status => Err(FetchError::Status(status)),
+		}
+	}
+
+	pub async fn fetch_input(&self, day: u64) -> Result<String, FetchError> {
+		eprintln!("Fetching inputs for day {day}...");
+
+		let body = self.send(self.request(Method::GET, &format!("/day/{day}/input"))).await?;
+
+		if body.contains("Please log in") {
+			Err(FetchError::LoggedOut)
+		} else if body.trim().is_empty() || body.trim_start().starts_with('<') {
+			Err(FetchError::UnexpectedBody)
+		} else {
+			Ok(body)
+		}
+	}
+
+	/// Checks the session against the settings page, which is small and only available when logged in. Returns the name of
+	/// the logged in user.
+	pub async fn fetch_user(&self) -> Result<String, FetchError> {
+		let request = self.client.request(Method::GET, format!("{}/settings", self.host));
+		let html = self.send(request).await?;
+
+		match Regex::new(r#"<div class="user">([^<]*)"#).unwrap().captures(&html) {
+			Some(captures) => Ok(decode_entities(captures.get(1).unwrap().as_str().trim())),
+			None => Err(FetchError::LoggedOut),
+		}
+	}
+
+	pub async fn fetch_page(&self, day: u64) -> Result<String, FetchError> {
+		eprintln!("Fetching the puzzle for day {day}...");
+
+		self.send(self.request(Method::GET, &format!("/day/{day}"))).await
+	}
+
+	pub async fn submit_answer(&self, day: u64, part: u8, answer: &Answer) -> Result<Option<Verdict>, FetchError> {
+		eprintln!("Submitting {answer} for day {day} part {part}...");
+
+		let request = self
+			.request(Method::POST, &format!("/day/{day}/answer"))
+			.form(&[("level", part.to_string()), ("answer", answer.to_string())]);
+
+		let html = self.send(request).await?;
+
+		Ok(Verdict::parse(&html))
+	}
+}
+
+impl From<reqwest::Error> for FetchError {
+	fn from(error: reqwest::Error) -> FetchError {
+		FetchError::Network(error)
+	}
+}
+
+impl Error for FetchError {}
+
+impl Display for FetchError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			FetchError::NoSession => write!(
+				f,
+				"This needs a session, so set ADVENT_OF_CODE_SESSION in the environment or .env, or session in the config"
+			),
+			FetchError::Network(error) => write!(f, "Couldn't reach Advent of Code: {error}"),
+			FetchError::LoggedOut => write!(
+				f,
+				"Advent of Code treated this as logged out, so the session needs refreshing. Log in again and copy the new session cookie."
+			),
+			FetchError::BadSession => write!(
+				f,
+				"Advent of Code rejected the session cookie, so it has probably expired. Log in again and copy the new session cookie."
+			),
+			FetchError::NotFound => write!(f, "Advent of Code couldn't find that puzzle; it may not be unlocked yet"),
+			FetchError::Status(status) => write!(f, "Advent of Code responded with {status}"),
+			FetchError::Throttled(wait) => write!(f, "Refusing to make another request for {:.1}s", wait.as_secs_f64()),
+			FetchError::Throttle(error) => write!(f, "Couldn't record the time of the request in the cache: {error}"),
+			FetchError::Cache(error) => write!(f, "Couldn't write what was fetched to the cache: {error}"),
+			FetchError::UnexpectedBody => write!(f, "Advent of Code responded with something that isn't a puzzle input"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{account::Account, cache::InputsCache};
+	use std::path::Path;
+	use tokio::{
+		io::{AsyncReadExt, AsyncWriteExt},
+		net::TcpListener,
+	};
+
+	// Accepts a single request, replies with `status` and `body`, and returns the raw request
+	async fn serve_once(listener: TcpListener, status: &'static str, body: &'static str) -> String {
+		let (mut stream, _) = listener.accept().await.unwrap();
+		let mut request = Vec::new();
+		let mut buffer = [0; 1024];
+
+		loop {
+			let read = stream.read(&mut buffer).await.unwrap();
+			request.extend_from_slice(&buffer[..read]);
+
+			let text = String::from_utf8_lossy(&request).to_string();
+
+			if let Some((head, content)) = text.split_once("\r\n\r\n") {
+				let length = head
+					.lines()
+					.find_map(|line| line.to_lowercase().strip_prefix("content-length: ").map(str::to_owned))
+					.map(|length| length.parse::<usize>().unwrap())
+					.unwrap_or(0);
+
+				if content.len() >= length {
+					break;
+				}
+			}
+		}
+
+		let response = format!(
+			"HTTP/1.1 {status}\r\nLocation: /\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+			body.len()
+		);
+		stream.write_all(response.as_bytes()).await.unwrap();
+
+		String::from_utf8(request).unwrap()
+	}
+
+	async fn test_client(directory: &Path, base_url: &str) -> (InputsCache, AdventClient) {
+		let account = Account {
+			name: "default".to_owned(),
+			session: Some("secret".to_owned()),
+		};
+
+		let cache = InputsCache::new(2023, Some(directory.to_owned()), &account).await.unwrap();
+		let throttle = Throttle::new(&cache, Duration::ZERO, false);
+		let client = AdventClient::new(base_url, 2023, account.session, "tests", throttle);
+
+		(cache, client)
+	}
+
+	// Fetches day 1's input through the cache, from a server that replies with `status` and `body`. Returns the result, and
+	// whatever ended up cached.
+	async fn fetch_input_from(status: &'static str, body: &'static str) -> (Result<String, FetchError>, Option<String>) {
+		let directory = tempfile::tempdir().unwrap();
+		let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let base_url = format!("http://{}", listener.local_addr().unwrap());
+		let server = tokio::spawn(serve_once(listener, status, body));
+
+		let (cache, client) = test_client(directory.path(), &base_url).await;
+		let result = crate::get_input(1, &cache, &client).await;
+		server.await.unwrap();
+
+		(result, cache.get(1).await)
+	}
+
+	#[tokio::test]
+	async fn submits_an_answer_to_the_base_url() {
+		let directory = tempfile::tempdir().unwrap();
+		let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let base_url = format!("http://{}", listener.local_addr().unwrap());
+		let server = tokio::spawn(serve_once(
+			listener,
+			"200 OK",
+			"<html><main><article><p>That's not the right answer; your answer is too high.</p></article></main></html>",
+		));
+
+		let (_, client) = test_client(directory.path(), &base_url).await;
+		let verdict = client.submit_answer(7, 2, &Answer::Number(1234)).await.unwrap();
+		let request = server.await.unwrap();
+
+		assert_eq!(verdict, Some(Verdict::TooHigh));
+		assert!(request.starts_with("POST /2023/day/7/answer "), "{request}");
+		assert!(request.contains("session=secret"), "{request}");
+		assert!(request.contains("user-agent: tests\r\n"), "{request}");
+		assert!(request.ends_with("level=2&answer=1234"), "{request}");
+	}
+
+	#[tokio::test]
+	async fn caches_a_genuine_input() {
+		let (result, cached) = fetch_input_from("200 OK", "1abc2\npqr3stu8vwx\n").await;
+
+		assert_eq!(result.unwrap(), "1abc2\npqr3stu8vwx\n");
+		assert_eq!(cached.as_deref(), Some("1abc2\npqr3stu8vwx\n"));
+	}
+
+	#[tokio::test]
+	async fn treats_a_redirect_as_logged_out() {
+		let (result, cached) = fetch_input_from("302 Found", "").await;
+
+		assert!(matches!(result, Err(FetchError::LoggedOut)));
+		assert_eq!(cached, None);
+	}
+
+	#[tokio::test]
+	async fn treats_a_bad_request_as_a_bad_session() {
+		let (result, cached) = fetch_input_from("400 Bad Request", "").await;
+
+		assert!(matches!(result, Err(FetchError::BadSession)));
+		assert_eq!(cached, None);
+	}
+
+	#[tokio::test]
+	async fn treats_not_found_as_locked() {
+		let (result, cached) = fetch_input_from("404 Not Found", "404 Not Found").await;
+
+		assert!(matches!(result, Err(FetchError::NotFound)));
+		assert_eq!(cached, None);
+	}
+
+	#[tokio::test]
+	async fn treats_the_login_message_as_logged_out() {
+		let (result, cached) = fetch_input_from("200 OK", "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n").await;
+
+		assert!(matches!(result, Err(FetchError::LoggedOut)));
+		assert_eq!(cached, None);
+	}
+
+	#[tokio::test]
+	async fn refuses_a_page_instead_of_an_input() {
+		let (result, cached) = fetch_input_from("200 OK", "<!DOCTYPE html>\n<html><body><main></main></body></html>").await;
+
+		assert!(matches!(result, Err(FetchError::UnexpectedBody)));
+		assert_eq!(cached, None);
+	}
+}
@@ -0,0 +1,149 @@
+use std::{collections::HashMap, env, path::PathBuf, time::Duration};
+
+use serde::Deserialize;
+use tokio::fs::read_to_string;
+
+use crate::account::Account;
+
+/// Settings read from `~/.config/advent_of_code_2023/config.toml`. Environment variables take precedence over the file, and
+/// variables from `.env` are treated as though they were set in the environment.
+#[derive(Deserialize, Default)]
+pub struct Config {
+	base_url: Option<String>,
+	year: Option<u64>,
+	user_agent: Option<String>,
+	contact: Option<String>,
+	request_interval: Option<u64>,
+	throttle: Option<ThrottleMode>,
+	session: Option<String>,
+	#[serde(default)]
+	accounts: HashMap<String, String>,
+	#[serde(skip)]
+	dotenv: HashMap<String, String>,
+}
+
+/// What to do with a request made less than `request_interval` seconds after the previous one
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ThrottleMode {
+	Delay,
+	Refuse,
+}
+
+impl Config {
+	pub async fn load(dotenv: HashMap<String, String>) -> Result<Config, String> {
+		let mut config = match config_dir() {
+			Some(dir) => {
+				let path = dir.join("config.toml");
+
+				match read_to_string(&path).await {
+					Ok(text) => toml::from_str(&text).map_err(|error| format!("Invalid config at {}: {error}", path.display()))?,
+					Err(_) => Config::default(),
+				}
+			}
+			None => Config::default(),
+		};
+
+		config.dotenv = dotenv;
+		Ok(config)
+	}
+
+	// The process environment is only ever read, since changing it while the runtime's threads are running isn't sound
+	fn var(&self, key: &str) -> Option<String> {
+		env::var(key).ok().or_else(|| self.dotenv.get(key).cloned())
+	}
+
+	pub fn base_url(&self) -> String {
+		self.var("ADVENT_OF_CODE_URL")
+			.or(self.base_url.clone())
+			.unwrap_or("https://adventofcode.com".to_owned())
+	}
+
+	pub fn year(&self) -> Result<u64, String> {
+		match self.var("ADVENT_OF_CODE_YEAR") {
+			Some(year) => year
+				.parse::<u64>()
+				.map_err(|_| format!("Expected ADVENT_OF_CODE_YEAR to be a number, got {year:?}")),
+			None => Ok(self.year.unwrap_or(2023)),
+		}
+	}
+
+	/// Identifies this tool to Advent of Code, as its maintainers ask of automated tools
+	pub fn user_agent(&self) -> String {
+		if let Some(user_agent) = self.var("ADVENT_OF_CODE_USER_AGENT").or(self.user_agent.clone()) {
+			return user_agent;
+		}
+
+		let repository = "github.com/Vehmloewff/advent_of_code_2023";
+
+		match self.var("ADVENT_OF_CODE_CONTACT").or(self.contact.clone()) {
+			Some(contact) => format!("{repository} by {contact}"),
+			None => repository.to_owned(),
+		}
+	}
+
+	pub fn request_interval(&self) -> Duration {
+		Duration::from_secs(self.request_interval.unwrap_or(5))
+	}
+
+	/// A named account's session comes from the `[accounts]` table. Without a name, the session comes from
+	/// `ADVENT_OF_CODE_SESSION`, falling back to `session` in the config.
+	pub fn account(&self, name: Option<&str>) -> Account {
+		match name {
+			Some(name) => Account {
+				name: name.to_owned(),
+				session: self.accounts.get(name).cloned(),
+			},
+			None => Account {
+				name: "default".to_owned(),
+				session: self.var("ADVENT_OF_CODE_SESSION").or(self.session.clone()),
+			},
+		}
+	}
+
+	pub fn throttle(&self) -> ThrottleMode {
+		self.throttle.unwrap_or(ThrottleMode::Delay)
+	}
+}
+
+/// Reads the variables from a `.env` file in the current directory, or failing that, in the repository
+pub async fn load_dotenv() -> HashMap<String, String> {
+	let mut variables = HashMap::new();
+
+	let text = match read_to_string(".env").await {
+		Ok(text) => text,
+		Err(_) => match read_to_string(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".env")).await {
+			Ok(text) => text,
+			Err(_) => return variables,
+		},
+	};
+
+	for line in text.lines() {
+		let line = line.trim();
+
+		if line.is_empty() || line.starts_with('#') {
+			continue;
+		}
+
+		let (key, value) = match line.trim_start_matches("export ").split_once('=') {
+			Some(pair) => pair,
+			None => continue,
+		};
+
+		let key = key.trim();
+		let value = value.trim().trim_matches('"').trim_matches('\'');
+
+		variables.insert(key.to_owned(), value.to_owned());
+	}
+
+	variables
+}
+
+fn config_dir() -> Option<PathBuf> {
+	let base = match env::var("XDG_CONFIG_HOME") {
+		Ok(dir) => PathBuf::from(dir),
+		Err(_) => PathBuf::from(env::var("HOME").ok()?).join(".config"),
+	};
+
+	Some(base.join("advent_of_code_2023"))
+}
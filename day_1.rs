@@ -1,25 +1,39 @@
+use crate::solution::{Answer, Solution};
 use crate::utils::{into_lines, sum};
 
-pub fn trebuchet(input: String) {
-    let lines = into_lines(input);
+pub struct Trebuchet {
+    lines: Vec<String>,
+}
+
+impl Solution for Trebuchet {
+    fn parse(input: String) -> Trebuchet {
+        Trebuchet {
+            lines: into_lines(input),
+        }
+    }
 
-    let values = lines
-        .iter()
-        .map(|line| decode_calibration_value(line))
-        .collect::<Vec<u64>>();
+    fn part_one(&self) -> Answer {
+        let values = self
+            .lines
+            .iter()
+            .map(|line| decode_calibration_value(line))
+            .collect::<Vec<u64>>();
 
-    let better_values = lines
-        .iter()
-        .map(|line| better_decode_calibration_value(line))
-        .collect::<Vec<u64>>();
+        sum(values).into()
+    }
 
-    let calibration_sum = sum(values);
-    let better_calibration_sum = sum(better_values);
+    fn part_two(&self) -> Answer {
+        let better_values = self
+            .lines
+            .iter()
+            .map(|line| better_decode_calibration_value(line))
+            .collect::<Vec<u64>>();
 
-    println!("sum={calibration_sum} better_sum={better_calibration_sum}");
+        sum(better_values).into()
+    }
 }
 
-fn decode_calibration_value(input: &String) -> u64 {
+fn decode_calibration_value(input: &str) -> u64 {
     let characters = input
         .chars()
         .filter(|character| character.is_numeric())
@@ -34,7 +48,7 @@ fn decode_calibration_value(input: &String) -> u64 {
     joined.parse::<u64>().unwrap()
 }
 
-fn better_decode_calibration_value(input: &String) -> u64 {
+fn better_decode_calibration_value(input: &str) -> u64 {
     // We do these weird double-replacements because we don't want to mess up any existing words
     // For example, removing a nine may mess up an eight: nineight
     let new_input = input
@@ -1,33 +1,46 @@
+use crate::solution::{Answer, Solution};
 use crate::utils::{into_lines, max, mul, sum};
 
-pub fn cube_conundrum(input: String) {
-    let limit = Drawing {
-        red: Some(12),
-        green: Some(13),
-        blue: Some(14),
-    };
-
-    let games = into_lines(input)
-        .iter()
-        .map(|line| Game::parse(line.to_owned()))
-        .collect::<Vec<Game>>();
-
-    let playable_game_ids = games
-        .iter()
-        .filter(|game| game.is_playable_with(&limit))
-        .map(|game| game.id)
-        .collect::<Vec<u64>>();
-
-    let lowest_drawing_powers = games
-        .iter()
-        .map(|game| game.get_lowest_counts().power())
-        .collect::<Vec<u64>>();
-
-    println!(
-        "playable_games_sum={} lowest_drawing_powers_sum={}",
-        sum(playable_game_ids),
-        sum(lowest_drawing_powers)
-    )
+pub struct CubeConundrum {
+    games: Vec<Game>,
+}
+
+impl Solution for CubeConundrum {
+    fn parse(input: String) -> CubeConundrum {
+        let games = into_lines(input)
+            .iter()
+            .map(|line| Game::parse(line.to_owned()))
+            .collect::<Vec<Game>>();
+
+        CubeConundrum { games }
+    }
+
+    fn part_one(&self) -> Answer {
+        let limit = Drawing {
+            red: Some(12),
+            green: Some(13),
+            blue: Some(14),
+        };
+
+        let playable_game_ids = self
+            .games
+            .iter()
+            .filter(|game| game.is_playable_with(&limit))
+            .map(|game| game.id)
+            .collect::<Vec<u64>>();
+
+        sum(playable_game_ids).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        let lowest_drawing_powers = self
+            .games
+            .iter()
+            .map(|game| game.get_lowest_counts().power())
+            .collect::<Vec<u64>>();
+
+        sum(lowest_drawing_powers).into()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -79,23 +92,17 @@ impl Game {
             red: Some(max(self
                 .drawings
                 .iter()
-                .map(|drawing| drawing.red)
-                .filter(|count| count.is_some())
-                .map(|count| count.unwrap())
+                .filter_map(|drawing| drawing.red)
                 .collect::<Vec<u64>>())),
             blue: Some(max(self
                 .drawings
                 .iter()
-                .map(|drawing| drawing.blue)
-                .filter(|count| count.is_some())
-                .map(|count| count.unwrap())
+                .filter_map(|drawing| drawing.blue)
                 .collect::<Vec<u64>>())),
             green: Some(max(self
                 .drawings
                 .iter()
-                .map(|drawing| drawing.green)
-                .filter(|count| count.is_some())
-                .map(|count| count.unwrap().clone())
+                .filter_map(|drawing| drawing.green)
                 .collect::<Vec<u64>>())),
         }
     }
@@ -146,16 +153,16 @@ impl Drawing {
     fn power(&self) -> u64 {
         let mut items = Vec::new();
 
-        if self.blue.is_some() {
-            items.push(self.blue.unwrap())
+        if let Some(blue) = self.blue {
+            items.push(blue)
         }
 
-        if self.green.is_some() {
-            items.push(self.green.unwrap())
+        if let Some(green) = self.green {
+            items.push(green)
         }
 
-        if self.red.is_some() {
-            items.push(self.red.unwrap())
+        if let Some(red) = self.red {
+            items.push(red)
         }
 
         mul(items)
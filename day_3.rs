@@ -1,25 +1,38 @@
+use crate::solution::{Answer, Solution};
 use crate::utils::sum;
 
-pub fn gear_ratios(input: String) {
-    let schematic = Schematic::parse(input);
-
-    let valid_part_numbers = schematic
-        .get_parts_with_symbols()
-        .iter()
-        .map(|part| part.number)
-        .collect::<Vec<u64>>();
-
-    let gear_ratios = schematic
-        .get_gears_with_parts()
-        .iter()
-        .map(|gear| gear.get_ratio())
-        .collect::<Vec<u64>>();
-
-    println!(
-        "valid_part_numbers={} gear_ratios={}",
-        sum(valid_part_numbers),
-        sum(gear_ratios)
-    );
+pub struct GearRatios {
+    schematic: Schematic,
+}
+
+impl Solution for GearRatios {
+    fn parse(input: String) -> GearRatios {
+        GearRatios {
+            schematic: Schematic::parse(input),
+        }
+    }
+
+    fn part_one(&self) -> Answer {
+        let valid_part_numbers = self
+            .schematic
+            .get_parts_with_symbols()
+            .iter()
+            .map(|part| part.number)
+            .collect::<Vec<u64>>();
+
+        sum(valid_part_numbers).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        let gear_ratios = self
+            .schematic
+            .get_gears_with_parts()
+            .iter()
+            .map(|gear| gear.get_ratio())
+            .collect::<Vec<u64>>();
+
+        sum(gear_ratios).into()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -96,7 +109,7 @@ impl Schematic {
 
         for part_number in &self.part_numbers {
             for symbol in &self.symbols {
-                if part_number.is_symbol_adjacent(&symbol) {
+                if part_number.is_symbol_adjacent(symbol) {
                     part_numbers_with_symbol.push(part_number);
                     break;
                 }
@@ -122,16 +135,18 @@ impl Schematic {
                     continue;
                 }
 
-                if part_1.is_none() {
-                    part_1 = Some(part_number)
-                } else if part_2.is_none() {
-                    part_2 = Some(part_number);
-
-                    gears.push(Gear {
-                        symbol,
-                        part_1: part_1.unwrap(),
-                        part_2: part_2.unwrap(),
-                    })
+                match (part_1, part_2) {
+                    (None, _) => part_1 = Some(part_number),
+                    (Some(first), None) => {
+                        part_2 = Some(part_number);
+
+                        gears.push(Gear {
+                            symbol,
+                            part_1: first,
+                            part_2: part_number,
+                        })
+                    }
+                    _ => (),
                 }
             }
         }
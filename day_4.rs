@@ -1,26 +1,38 @@
 use std::collections::HashMap;
 
+use crate::solution::{Answer, Solution};
 use crate::utils::{into_lines, max, min, sum};
 
-pub fn scratchcards(input: String) {
-    let scratchcards = into_lines(input)
-        .iter()
-        .map(|line| Scratchcard::parse(line))
-        .collect::<Vec<Scratchcard>>();
-
-    let scores = scratchcards
-        .iter()
-        .map(|scratchcard| scratchcard.get_score())
-        .collect::<Vec<u64>>();
-
-    let mut pad = Scratchpad::new(scratchcards);
-    pad.copy_scratchcards();
-
-    println!(
-        "scores={} total_cards={}",
-        sum(scores),
-        pad.get_total_cards()
-    )
+pub struct Scratchcards {
+    scratchcards: Vec<Scratchcard>,
+}
+
+impl Solution for Scratchcards {
+    fn parse(input: String) -> Scratchcards {
+        let scratchcards = into_lines(input)
+            .iter()
+            .map(|line| Scratchcard::parse(line))
+            .collect::<Vec<Scratchcard>>();
+
+        Scratchcards { scratchcards }
+    }
+
+    fn part_one(&self) -> Answer {
+        let scores = self
+            .scratchcards
+            .iter()
+            .map(|scratchcard| scratchcard.get_score())
+            .collect::<Vec<u64>>();
+
+        sum(scores).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        let mut pad = Scratchpad::new(self.scratchcards.clone());
+        pad.copy_scratchcards();
+
+        pad.get_total_cards().into()
+    }
 }
 
 pub struct Scratchpad {
@@ -78,6 +90,7 @@ impl Scratchpad {
     }
 }
 
+#[derive(Clone)]
 pub struct Scratchcard {
     card_number: u64,
     winning_numbers: Vec<u64>,
@@ -85,7 +98,7 @@ pub struct Scratchcard {
 }
 
 impl Scratchcard {
-    pub fn parse(input: &String) -> Scratchcard {
+    pub fn parse(input: &str) -> Scratchcard {
         let card_basics = input.split(":").collect::<Vec<&str>>();
         let card_number = card_basics
             .first()
@@ -102,7 +115,6 @@ impl Scratchcard {
         let winning_numbers = sections
             .first()
             .unwrap()
-            .to_owned()
             .split(" ")
             .filter(|item| !item.is_empty())
             .map(|item| item.trim().parse::<u64>().unwrap())
@@ -111,7 +123,6 @@ impl Scratchcard {
         let real_numbers = sections
             .last()
             .unwrap()
-            .to_owned()
             .split(" ")
             .filter(|item| !item.is_empty())
             .map(|item| item.trim().parse::<u64>().unwrap())
@@ -128,11 +139,11 @@ impl Scratchcard {
         let mut winning_count = 0;
 
         for real_number in &self.real_numbers {
-            if self.winning_numbers.contains(&real_number) {
+            if self.winning_numbers.contains(real_number) {
                 if winning_count == 0 {
                     winning_count = 1;
                 } else {
-                    winning_count = winning_count * 2;
+                    winning_count *= 2;
                 }
             };
         }
@@ -144,7 +155,7 @@ impl Scratchcard {
         let mut winning_count = 0;
 
         for real_number in &self.real_numbers {
-            if self.winning_numbers.contains(&real_number) {
+            if self.winning_numbers.contains(real_number) {
                 winning_count += 1;
             };
         }
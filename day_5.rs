@@ -1,25 +1,40 @@
 use core::panic;
 
+use crate::solution::{Answer, Solution};
 use crate::utils::{collect_numbers, min, nest_vector, split};
 use regex::Regex;
 
-pub fn seeds(input: String) {
-	let seed_list = SeedList::from_str(&input);
-	let mappings = MappingBuilder(Mapping::many_from_str(&input));
+pub struct Seeds {
+	seed_list: SeedList,
+	mappings: MappingBuilder,
+}
 
-	let incorrect_seeds = &seed_list.0;
-	let incorrect_locations = mappings.map("seed", "location", incorrect_seeds.clone());
+impl Solution for Seeds {
+	fn parse(input: String) -> Seeds {
+		Seeds {
+			seed_list: SeedList::parse(&input),
+			mappings: MappingBuilder(Mapping::many_from_str(&input)),
+		}
+	}
 
-	let seed_bottoms = mappings
-		.get_seed_bottoms()
-		.iter()
-		.map(|seed| seed.to_owned())
-		.filter(|seed| seed_list.is_within_list(seed.to_owned()))
-		.collect::<Vec<u64>>();
+	fn part_one(&self) -> Answer {
+		let incorrect_locations = self.mappings.map("seed", "location", self.seed_list.0.clone());
 
-	let locations = mappings.map("seed", "location", seed_bottoms);
+		min(incorrect_locations).into()
+	}
 
-	println!("closest_location={} real_locations={}", min(incorrect_locations), min(locations));
+	fn part_two(&self) -> Answer {
+		let seed_bottoms = self
+			.mappings
+			.get_seed_bottoms()
+			.into_iter()
+			.filter(|seed| self.seed_list.is_within_list(*seed))
+			.collect::<Vec<u64>>();
+
+		let locations = self.mappings.map("seed", "location", seed_bottoms);
+
+		min(locations).into()
+	}
 }
 
 #[derive(Debug, PartialEq)]
@@ -38,11 +53,11 @@ impl VirtualRange {
 pub struct SeedList(Vec<u64>);
 
 impl SeedList {
-	pub fn from_str(input: &str) -> SeedList {
+	pub fn parse(input: &str) -> SeedList {
 		let captures = Regex::new(r"seeds:((?:\s\d+)+)").unwrap().captures(input).unwrap();
 
 		let codes = captures.get(1).unwrap().as_str().to_owned();
-		let list = collect_numbers(split(codes.to_owned(), &[' ']));
+		let list = collect_numbers(split(codes, &[' ']));
 
 		SeedList(list)
 	}
@@ -86,13 +101,9 @@ impl MappingBuilder {
 		let source_str: String = source.into();
 		let destination_str: String = destination.into();
 
-		for mapping in &self.0 {
-			if &mapping.source == &source_str && &mapping.destination == &destination_str {
-				return Some(mapping);
-			}
-		}
-
-		None
+		self.0
+			.iter()
+			.find(|mapping| mapping.source == source_str && mapping.destination == destination_str)
 	}
 
 	pub fn infer_mapping_strategy(&self, from: &str, to: &str) -> MappingStrategy {
@@ -116,7 +127,7 @@ impl MappingBuilder {
 
 			mappings.push((last_thing, local_to.unwrap().into()));
 
-			if &local_to.unwrap() == &to {
+			if local_to.unwrap() == to {
 				break true;
 			}
 		};
@@ -145,7 +156,7 @@ impl MappingBuilder {
 
 			reverse_mappings.push((local_from.unwrap().into(), last_thing));
 
-			if &local_from.unwrap() == &to {
+			if local_from.unwrap() == to {
 				break true;
 			}
 		};
@@ -212,7 +223,7 @@ impl Mapping {
 			let codes = captures.get(3).unwrap().as_str();
 			let mut entries = MappingEntry::many_from_str(codes);
 
-			entries.sort_by(|a, b| a.source_start.cmp(&b.source_start));
+			entries.sort_by_key(|entry| entry.source_start);
 
 			mappings.push(Mapping {
 				source,
@@ -228,12 +239,9 @@ impl Mapping {
 		let mut entry_mapping = None;
 
 		for entry in &self.entries {
-			match entry.map(source_code) {
-				Some(code) => {
-					entry_mapping = Some(code);
-					break;
-				}
-				None => (),
+			if let Some(code) = entry.map(source_code) {
+				entry_mapping = Some(code);
+				break;
 			}
 		}
 
@@ -251,12 +259,9 @@ impl Mapping {
 		let mut entry_mapping = None;
 
 		for entry in &self.entries {
-			match entry.map_reverse(source_code) {
-				Some(code) => {
-					entry_mapping = Some(code);
-					break;
-				}
-				None => (),
+			if let Some(code) = entry.map_reverse(source_code) {
+				entry_mapping = Some(code);
+				break;
 			}
 		}
 
@@ -288,7 +293,7 @@ impl MappingEntry {
 		let mut entries = Vec::new();
 
 		for number_group in number_groups {
-			let destination_start = number_group.get(0).unwrap().to_owned();
+			let destination_start = number_group.first().unwrap().to_owned();
 			let source_start = number_group.get(1).unwrap().to_owned();
 			let length = number_group.get(2).unwrap().to_owned();
 
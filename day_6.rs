@@ -1,22 +1,34 @@
+use crate::solution::{Answer, Solution};
 use crate::utils::{collect_numbers, into_lines, mul, safe_sub, split};
 
-pub fn wait_for_it(input: String) {
-	let races = parse_out_races(input.clone());
-	let winning_counts = races.iter().map(|race| race.get_winning_count()).collect::<Vec<u64>>();
+pub struct WaitForIt {
+	races: Vec<Race>,
+	one_race: Race,
+}
 
-	let one_race = parse_out_races_better(input);
+impl Solution for WaitForIt {
+	fn parse(input: String) -> WaitForIt {
+		WaitForIt {
+			races: parse_out_races(input.clone()),
+			one_race: parse_out_races_better(input),
+		}
+	}
 
-	println!(
-		"winning_counts={} single_winning={}",
-		mul(winning_counts),
-		one_race.get_winning_count()
-	)
+	fn part_one(&self) -> Answer {
+		let winning_counts = self.races.iter().map(|race| race.get_winning_count()).collect::<Vec<u64>>();
+
+		mul(winning_counts).into()
+	}
+
+	fn part_two(&self) -> Answer {
+		self.one_race.get_winning_count().into()
+	}
 }
 
 fn parse_out_races(input: String) -> Vec<Race> {
 	let lines = into_lines(input);
 	let mut times = collect_numbers(split(
-		split(lines.get(0).unwrap().to_owned(), &[':']).last().unwrap().to_owned(),
+		split(lines.first().unwrap().to_owned(), &[':']).last().unwrap().to_owned(),
 		&[' '],
 	));
 	let mut distances = collect_numbers(split(
@@ -25,11 +37,7 @@ fn parse_out_races(input: String) -> Vec<Race> {
 	));
 	let mut races = Vec::new();
 
-	loop {
-		let time = match times.pop() {
-			Some(time) => time,
-			None => break,
-		};
+	while let Some(time) = times.pop() {
 		let distance = match distances.pop() {
 			Some(distance) => distance,
 			None => break,
@@ -44,7 +52,7 @@ fn parse_out_races(input: String) -> Vec<Race> {
 fn parse_out_races_better(input: String) -> Race {
 	let lines = into_lines(input);
 
-	let time = split(lines.get(0).unwrap().to_owned(), &[':'])
+	let time = split(lines.first().unwrap().to_owned(), &[':'])
 		.last()
 		.unwrap()
 		.to_owned()
@@ -1,23 +1,38 @@
+use crate::solution::{Answer, Solution};
 use crate::utils::{into_lines, split};
 use core::panic;
 use std::{cmp::Ordering, collections::HashMap};
 
-pub fn camel_cards(input: String) {
-	let mut hands = parse_hands(input);
+pub struct CamelCards {
+	hands: Vec<Hand>,
+}
 
-	// Sort hands in reverse order so that the index will correspond to rank
-	hands.sort_by(|a, b| b.cmp(a));
+impl Solution for CamelCards {
+	fn parse(input: String) -> CamelCards {
+		CamelCards { hands: parse_hands(input) }
+	}
 
-	let mut winnings = 0;
+	// Part one was solved before jokers were introduced, and the card rules have since been rewritten for part two
+	fn part_one(&self) -> Answer {
+		Answer::Unsolved
+	}
 
-	for index in 0..hands.len() {
-		let hand = hands.get(index).unwrap();
-		let rank = index + 1;
+	fn part_two(&self) -> Answer {
+		let mut hands = self.hands.clone();
 
-		winnings += hand.get_winnings(rank as u64);
-	}
+		// Sort hands in reverse order so that the index will correspond to rank
+		hands.sort_by(|a, b| b.cmp(a));
 
-	println!("total_winnings={winnings}");
+		let mut winnings = 0;
+
+		for (index, hand) in hands.iter().enumerate() {
+			let rank = index + 1;
+
+			winnings += hand.get_winnings(rank as u64);
+		}
+
+		winnings.into()
+	}
 }
 
 fn parse_hands(input: String) -> Vec<Hand> {
@@ -67,10 +82,10 @@ impl HandType {
 		counts
 	}
 
-	fn get_card_with_count<'a>(counts: &'a HashMap<Card, u64>, count: u64) -> Option<&'a Card> {
+	fn get_card_with_count(counts: &HashMap<Card, u64>, count: u64) -> Option<&Card> {
 		for (key, value) in counts.iter() {
 			if value == &count {
-				return Some(&key);
+				return Some(key);
 			}
 		}
 
@@ -79,7 +94,7 @@ impl HandType {
 
 	pub fn get_card_with_frequency(frequency: u64, cards: &[Card; 5]) -> Option<Card> {
 		let mut counts = HandType::get_card_counts(cards);
-		let jokers_available = counts.get(&Card::Joker).unwrap_or(&0).clone();
+		let jokers_available = *counts.get(&Card::Joker).unwrap_or(&0);
 
 		counts.remove(&Card::Joker);
 
@@ -91,19 +106,13 @@ impl HandType {
 	}
 
 	pub fn get_cards_with_frequencies(frequency1: u64, frequency2: u64, cards: &[Card; 5]) -> Option<(Card, Card)> {
-		let card_1 = match HandType::get_card_with_frequency(frequency1, cards) {
-			Some(card) => card,
-			None => return None,
-		};
+		let card_1 = HandType::get_card_with_frequency(frequency1, cards)?;
 
 		let mut counts = HandType::get_card_counts(cards);
 		counts.remove(&Card::Joker);
 		counts.remove(&card_1);
 
-		match HandType::get_card_with_count(&counts, frequency2) {
-			Some(card_2) => Some((card_1, card_2.clone())),
-			None => None,
-		}
+		HandType::get_card_with_count(&counts, frequency2).map(|card_2| (card_1, card_2.clone()))
 	}
 
 	pub fn from_cards(cards: &[Card; 5]) -> HandType {
@@ -146,7 +155,7 @@ impl Card {
 	}
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 struct Hand {
 	cards: [Card; 5],
 	bid: u64,
@@ -160,7 +169,7 @@ impl Hand {
 			.first()
 			.unwrap()
 			.chars()
-			.map(|character| Card::from_char(character))
+			.map(Card::from_char)
 			.collect::<Vec<Card>>();
 
 		cards_vec.reverse();
@@ -187,6 +196,12 @@ impl Hand {
 	}
 }
 
+impl PartialOrd for Hand {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
 impl Ord for Hand {
 	fn cmp(&self, other: &Self) -> Ordering {
 		let type_ordering = self.get_type().cmp(&other.get_type());
@@ -1,20 +1,35 @@
+use crate::solution::{Answer, Solution};
 use crate::utils::{into_lines, least_common_multiple, split};
 use std::{collections::HashMap, str::FromStr};
 
-pub fn haunted_wasteland(input: String) {
-	let mut lines = into_lines(input);
-	let directions = lines.remove(0).parse::<Directions>().unwrap();
-	let network = Network::from_lines(lines);
-	let starting_codes = network.get_codes_ending_with('A');
-	let ending_codes = network.get_codes_ending_with('Z');
-	let basic_steps = directions.map("AAA".to_string(), &["ZZZ".to_string()], &network);
-	let group_steps = starting_codes
-		.iter()
-		.map(|code| directions.map(code.clone(), &ending_codes, &network))
-		.collect::<Vec<u64>>();
-	let complex_steps = least_common_multiple(group_steps);
-
-	println!("basic_steps={basic_steps} complex_steps={complex_steps}");
+pub struct HauntedWasteland {
+	directions: Directions,
+	network: Network,
+}
+
+impl Solution for HauntedWasteland {
+	fn parse(input: String) -> HauntedWasteland {
+		let mut lines = into_lines(input);
+		let directions = lines.remove(0).parse::<Directions>().unwrap();
+		let network = Network::from_lines(lines);
+
+		HauntedWasteland { directions, network }
+	}
+
+	fn part_one(&self) -> Answer {
+		self.directions.map("AAA".to_string(), &["ZZZ".to_string()], &self.network).into()
+	}
+
+	fn part_two(&self) -> Answer {
+		let starting_codes = self.network.get_codes_ending_with('A');
+		let ending_codes = self.network.get_codes_ending_with('Z');
+		let group_steps = starting_codes
+			.iter()
+			.map(|code| self.directions.map(code.clone(), &ending_codes, &self.network))
+			.collect::<Vec<u64>>();
+
+		least_common_multiple(group_steps).into()
+	}
 }
 
 pub struct Network(HashMap<String, (String, String)>);
@@ -68,7 +83,7 @@ impl Directions {
 
 		loop {
 			let direction_index = index % self.0.len() as u64;
-			let direction = self.0.get(direction_index as usize).unwrap().clone();
+			let direction = *self.0.get(direction_index as usize).unwrap();
 
 			if to.contains(last_destination) {
 				break;
@@ -0,0 +1,98 @@
+use std::path::PathBuf;
+
+use tokio::fs::{create_dir_all, read_to_string, write};
+
+/// An example from a puzzle description, along with the answer the description gives for it
+#[derive(Debug, Clone)]
+pub struct Example {
+	pub part: u8,
+	pub input: String,
+	pub expected: String,
+}
+
+/// Examples are stored in the repository as `fixtures/day_{day}_part_{part}.txt`, next to a `.expected` file holding the answer
+pub struct Fixtures {
+	path: PathBuf,
+}
+
+impl Fixtures {
+	pub fn new() -> Fixtures {
+		Fixtures::at(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures"))
+	}
+
+	fn at(path: PathBuf) -> Fixtures {
+		Fixtures { path }
+	}
+
+	fn file(&self, day: u64, part: u8, extension: &str) -> PathBuf {
+		self.path.join(format!("day_{day}_part_{part}.{extension}"))
+	}
+
+	pub async fn get(&self, day: u64) -> Vec<Example> {
+		let mut examples = Vec::new();
+
+		for part in [1, 2] {
+			let input = read_to_string(self.file(day, part, "txt")).await;
+			let expected = read_to_string(self.file(day, part, "expected")).await;
+
+			if let (Ok(input), Ok(expected)) = (input, expected) {
+				examples.push(Example {
+					part,
+					input,
+					expected: expected.trim().to_owned(),
+				})
+			}
+		}
+
+		examples
+	}
+
+	pub async fn set(&self, day: u64, examples: &[Example]) {
+		create_dir_all(&self.path).await.unwrap();
+
+		for example in examples {
+			write(self.file(day, example.part, "txt"), &example.input).await.unwrap();
+			write(self.file(day, example.part, "expected"), format!("{}\n", example.expected)).await.unwrap();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn example(part: u8, input: &str, expected: &str) -> Example {
+		Example {
+			part,
+			input: input.to_owned(),
+			expected: expected.to_owned(),
+		}
+	}
+
+	#[tokio::test]
+	async fn round_trips_examples() {
+		let directory = tempfile::tempdir().unwrap();
+		let fixtures = Fixtures::at(directory.path().join("fixtures"));
+
+		fixtures.set(1, &[example(1, "1abc2\n", "142"), example(2, "two1nine\n", "281")]).await;
+
+		let examples = fixtures.get(1).await;
+		assert_eq!(examples.len(), 2);
+		assert_eq!(examples[0].input, "1abc2\n");
+		assert_eq!(examples[1].expected, "281");
+		assert!(fixtures.get(2).await.is_empty());
+	}
+
+	#[tokio::test]
+	async fn trims_answers_edited_by_hand() {
+		let directory = tempfile::tempdir().unwrap();
+		let fixtures = Fixtures::at(directory.path().to_owned());
+
+		fixtures.set(4, &[example(1, "input\n", "13")]).await;
+		write(fixtures.file(4, 1, "expected"), "  30\n\n").await.unwrap();
+
+		let examples = fixtures.get(4).await;
+		assert_eq!(examples.len(), 1);
+		assert_eq!(examples[0].expected, "30");
+	}
+}
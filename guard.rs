@@ -0,0 +1,89 @@
+use std::{
+	any::Any,
+	fmt::{self, Display},
+	panic,
+	sync::mpsc,
+	thread,
+	time::Duration,
+};
+
+/// How running some solver code went, when it might panic or never finish
+pub enum Outcome<T> {
+	Finished(T),
+	Panicked(String),
+	TimedOut,
+}
+
+/// Runs `f` on its own thread, catching panics. A thread that times out is left running, but is detached so it can't keep the
+/// program from exiting.
+pub fn run_guarded<T, F>(timeout: Option<Duration>, f: F) -> Outcome<T>
+where
+	T: Send + 'static,
+	F: FnOnce() -> T + Send + 'static,
+{
+	let (sender, receiver) = mpsc::channel();
+
+	thread::spawn(move || {
+		let _ = sender.send(panic::catch_unwind(panic::AssertUnwindSafe(f)));
+	});
+
+	let result = match timeout {
+		Some(timeout) => match receiver.recv_timeout(timeout) {
+			Ok(result) => result,
+			Err(_) => return Outcome::TimedOut,
+		},
+		None => receiver.recv().unwrap(),
+	};
+
+	match result {
+		Ok(value) => Outcome::Finished(value),
+		Err(payload) => Outcome::Panicked(panic_message(payload)),
+	}
+}
+
+/// Runs `f` on tokio's blocking pool, catching panics, so that many can run at once
+pub async fn run_pooled<T, F>(f: F) -> Outcome<T>
+where
+	T: Send + 'static,
+	F: FnOnce() -> T + Send + 'static,
+{
+	match tokio::task::spawn_blocking(f).await {
+		Ok(value) => Outcome::Finished(value),
+		Err(error) => Outcome::Panicked(panic_message(error.into_panic())),
+	}
+}
+
+impl<T> Outcome<T> {
+	pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Outcome<U> {
+		match self {
+			Outcome::Finished(value) => Outcome::Finished(f(value)),
+			Outcome::Panicked(message) => Outcome::Panicked(message),
+			Outcome::TimedOut => Outcome::TimedOut,
+		}
+	}
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+	if let Some(message) = payload.downcast_ref::<&str>() {
+		message.to_string()
+	} else if let Some(message) = payload.downcast_ref::<String>() {
+		message.clone()
+	} else {
+		"unknown panic".to_owned()
+	}
+}
+
+/// Keeps panics from printing to stderr, for when they are going to be reported some other way
+pub fn silence_panics() {
+	panic::set_hook(Box::new(|_| {}));
+}
+
+impl<T: Display> Display for Outcome<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Outcome::Finished(value) => write!(f, "{value}"),
+			Outcome::Panicked(message) => write!(f, "panicked: {message}"),
+			Outcome::TimedOut => write!(f, "timed out"),
+		}
+	}
+}
@@ -0,0 +1,11 @@
+/// FNV-1a, which unlike the standard library's hasher is guaranteed to stay the same between releases
+pub fn stable_hash(text: &str) -> String {
+	let mut hash: u64 = 0xcbf29ce484222325;
+
+	for byte in text.bytes() {
+		hash ^= byte as u64;
+		hash = hash.wrapping_mul(0x100000001b3);
+	}
+
+	format!("{hash:016x}")
+}
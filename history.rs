@@ -0,0 +1,169 @@
+use std::{
+	path::PathBuf,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use advent_of_code_2023::Samples;
+use serde::{Deserialize, Serialize};
+use tokio::{
+	fs::{read_to_string, OpenOptions},
+	io::AsyncWriteExt,
+	process::Command,
+};
+
+use crate::cache::InputsCache;
+
+/// Every benchmark that has been run, one JSON object per line. It is shared between accounts, so each run records a hash of
+/// its input, and is only ever compared against runs on the same input.
+pub struct BenchHistory {
+	path: PathBuf,
+}
+
+/// The statistics for one stage of a benchmark run, where the stage is "parse", "part 1" or "part 2". Times are in nanoseconds.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct BenchRecord {
+	pub day: u64,
+	pub stage: String,
+	pub input_hash: String,
+	pub commit: String,
+	pub name: Option<String>,
+	pub timestamp: u64,
+	pub iterations: u32,
+	pub min: f64,
+	pub median: f64,
+	pub mean: f64,
+	pub stddev: f64,
+}
+
+#[derive(PartialEq, Eq)]
+pub enum Change {
+	Slower,
+	Faster,
+	Insignificant,
+}
+
+impl BenchHistory {
+	pub fn new(cache: &InputsCache) -> BenchHistory {
+		BenchHistory {
+			path: cache.root().join("bench_history.jsonl"),
+		}
+	}
+
+	// Lines that can't be read are skipped, rather than losing the rest of the history
+	pub async fn records(&self) -> Vec<BenchRecord> {
+		let text = read_to_string(&self.path).await.unwrap_or_default();
+
+		text.lines().filter_map(|line| serde_json::from_str(line).ok()).collect()
+	}
+
+	pub async fn append(&self, records: &[BenchRecord]) {
+		let mut file = OpenOptions::new().create(true).append(true).open(&self.path).await.unwrap();
+
+		for record in records {
+			let line = format!("{}\n", serde_json::to_string(record).unwrap());
+			file.write_all(line.as_bytes()).await.unwrap();
+		}
+	}
+
+	/// The latest run of a day's stage on the same input, or if `baseline` is given, the latest one that was named that or made
+	/// at that commit
+	pub async fn baseline(&self, record: &BenchRecord, baseline: Option<&str>) -> Option<BenchRecord> {
+		self.records().await.into_iter().rev().find(|previous| {
+			let matches_baseline = match baseline {
+				Some(baseline) => previous.name.as_deref() == Some(baseline) || previous.commit.starts_with(baseline),
+				None => true,
+			};
+
+			previous.day == record.day && previous.stage == record.stage && previous.input_hash == record.input_hash && matches_baseline
+		})
+	}
+}
+
+impl BenchRecord {
+	pub fn new(day: u64, stage: &str, samples: &Samples, input_hash: &str, commit: &str, name: Option<&str>) -> BenchRecord {
+		BenchRecord {
+			day,
+			stage: stage.to_owned(),
+			input_hash: input_hash.to_owned(),
+			commit: commit.to_owned(),
+			name: name.map(str::to_owned),
+			timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
+			iterations: samples.len() as u32,
+			min: samples.min(),
+			median: samples.median(),
+			mean: samples.mean(),
+			stddev: samples.stddev(),
+		}
+	}
+
+	/// Whether this run's mean differs from the baseline's by more than twice the standard error of the difference, which is
+	/// roughly a 95% confidence that the change is real
+	pub fn compare(&self, baseline: &BenchRecord) -> Change {
+		let variance = |record: &BenchRecord| record.stddev.powi(2) / record.iterations as f64;
+		let standard_error = (variance(self) + variance(baseline)).sqrt();
+		let difference = self.mean - baseline.mean;
+
+		if difference.abs() <= 2.0 * standard_error {
+			Change::Insignificant
+		} else if difference > 0.0 {
+			Change::Slower
+		} else {
+			Change::Faster
+		}
+	}
+}
+
+/// The commit that is checked out in the repository, marked as dirty if there are uncommitted changes. Git is pointed at the
+/// repository so that this doesn't depend on the working directory.
+pub async fn current_commit() -> String {
+	let output = Command::new("git")
+		.args(["-C", env!("CARGO_MANIFEST_DIR"), "describe", "--always", "--dirty"])
+		.output()
+		.await;
+
+	match output {
+		Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout).trim().to_owned(),
+		_ => "unknown".to_owned(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::account::Account;
+
+	fn record(input_hash: &str, commit: &str) -> BenchRecord {
+		BenchRecord {
+			day: 5,
+			stage: "part 2".to_owned(),
+			input_hash: input_hash.to_owned(),
+			commit: commit.to_owned(),
+			name: None,
+			timestamp: 0,
+			iterations: 10,
+			min: 1.0,
+			median: 1.0,
+			mean: 1.0,
+			stddev: 0.0,
+		}
+	}
+
+	#[tokio::test]
+	async fn only_compares_runs_on_the_same_input() {
+		let directory = tempfile::tempdir().unwrap();
+		let account = Account {
+			name: "default".to_owned(),
+			session: Some("secret".to_owned()),
+		};
+
+		let cache = InputsCache::new(2023, Some(directory.path().to_owned()), &account).await.unwrap();
+		let history = BenchHistory::new(&cache);
+		history.append(&[record("alice", "aaaaaaa"), record("bob", "bbbbbbb")]).await;
+
+		let baseline = history.baseline(&record("alice", "ccccccc"), None).await.unwrap();
+		assert_eq!(baseline.commit, "aaaaaaa");
+
+		assert!(history.baseline(&record("alice", "ccccccc"), Some("bbbbbbb")).await.is_none());
+		assert!(history.baseline(&record("carol", "ccccccc"), None).await.is_none());
+	}
+}
@@ -0,0 +1,215 @@
+use std::{
+	fmt::{self, Display},
+	path::PathBuf,
+};
+
+use advent_of_code_2023::Answer;
+use tokio::fs::{read_to_string, write};
+
+use crate::{cache::InputsCache, submit::Verdict};
+
+/// Every answer submitted for a day's part, along with the verdict it got. Stored beside the cached inputs.
+pub struct GuessLedger {
+	path: PathBuf,
+}
+
+#[derive(Debug, Clone)]
+pub struct Guess {
+	pub answer: String,
+	pub verdict: Verdict,
+}
+
+/// Why a guess was refused without submitting it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+	AlreadySolved(String),
+	KnownWrong,
+	AtOrAbove(u64),
+	AtOrBelow(u64),
+}
+
+impl GuessLedger {
+	pub fn new(cache: &InputsCache) -> GuessLedger {
+		GuessLedger {
+			path: cache.path().to_owned(),
+		}
+	}
+
+	fn file(&self, day: u64, part: u8) -> PathBuf {
+		self.path.join(format!("day_{day}_part_{part}_guesses.txt"))
+	}
+
+	pub async fn guesses(&self, day: u64, part: u8) -> Vec<Guess> {
+		let text = read_to_string(self.file(day, part)).await.unwrap_or_default();
+
+		text.lines()
+			.filter_map(|line| {
+				let (verdict, answer) = line.split_once(' ')?;
+
+				Some(Guess {
+					answer: answer.to_owned(),
+					verdict: parse_verdict(verdict)?,
+				})
+			})
+			.collect()
+	}
+
+	/// Only verdicts about the answer itself are recorded, so rate limits and already-solved replies are skipped
+	pub async fn record(&self, day: u64, part: u8, answer: &Answer, verdict: &Verdict) {
+		let verdict = match format_verdict(verdict) {
+			Some(verdict) => verdict,
+			None => return,
+		};
+
+		let mut text = read_to_string(self.file(day, part)).await.unwrap_or_default();
+		text.push_str(&format!("{verdict} {answer}\n"));
+
+		write(self.file(day, part), text).await.unwrap()
+	}
+
+	pub async fn check(&self, day: u64, part: u8, answer: &Answer) -> Result<(), Rejection> {
+		let answer = answer.to_string();
+		let guesses = self.guesses(day, part).await;
+
+		if let Some(correct) = guesses.iter().find(|guess| guess.verdict == Verdict::Correct) {
+			return Err(Rejection::AlreadySolved(correct.answer.clone()));
+		}
+
+		if guesses.iter().any(|guess| guess.answer == answer) {
+			return Err(Rejection::KnownWrong);
+		}
+
+		let number = match answer.parse::<u64>() {
+			Ok(number) => number,
+			Err(_) => return Ok(()),
+		};
+
+		let lowest_too_high = guesses
+			.iter()
+			.filter(|guess| guess.verdict == Verdict::TooHigh)
+			.filter_map(|guess| guess.answer.parse::<u64>().ok())
+			.min();
+
+		let highest_too_low = guesses
+			.iter()
+			.filter(|guess| guess.verdict == Verdict::TooLow)
+			.filter_map(|guess| guess.answer.parse::<u64>().ok())
+			.max();
+
+		if let Some(bound) = lowest_too_high {
+			if number >= bound {
+				return Err(Rejection::AtOrAbove(bound));
+			}
+		}
+
+		if let Some(bound) = highest_too_low {
+			if number <= bound {
+				return Err(Rejection::AtOrBelow(bound));
+			}
+		}
+
+		Ok(())
+	}
+}
+
+fn format_verdict(verdict: &Verdict) -> Option<&'static str> {
+	match verdict {
+		Verdict::Correct => Some("correct"),
+		Verdict::TooHigh => Some("too_high"),
+		Verdict::TooLow => Some("too_low"),
+		Verdict::Wrong => Some("wrong"),
+		Verdict::RateLimited(_) | Verdict::AlreadySolved => None,
+	}
+}
+
+fn parse_verdict(text: &str) -> Option<Verdict> {
+	match text {
+		"correct" => Some(Verdict::Correct),
+		"too_high" => Some(Verdict::TooHigh),
+		"too_low" => Some(Verdict::TooLow),
+		"wrong" => Some(Verdict::Wrong),
+		_ => None,
+	}
+}
+
+impl Display for Rejection {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Rejection::AlreadySolved(answer) => write!(f, "This part was already solved with {answer}"),
+			Rejection::KnownWrong => write!(f, "This answer was already submitted and was wrong"),
+			Rejection::AtOrAbove(bound) => write!(f, "{bound} was already too high, so this answer must be lower"),
+			Rejection::AtOrBelow(bound) => write!(f, "{bound} was already too low, so this answer must be higher"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::account::Account;
+	use tempfile::TempDir;
+
+	async fn ledger_with(guesses: &[(&str, Verdict)]) -> (TempDir, GuessLedger) {
+		let directory = tempfile::tempdir().unwrap();
+		let account = Account {
+			name: "default".to_owned(),
+			session: None,
+		};
+
+		let cache = InputsCache::new(2023, Some(directory.path().to_owned()), &account).await.unwrap();
+		let ledger = GuessLedger::new(&cache);
+
+		for (answer, verdict) in guesses {
+			ledger.record(1, 1, &Answer::Text(answer.to_string()), verdict).await;
+		}
+
+		(directory, ledger)
+	}
+
+	#[tokio::test]
+	async fn refuses_a_known_wrong_answer() {
+		let (_directory, ledger) = ledger_with(&[("abc", Verdict::Wrong)]).await;
+
+		assert_eq!(
+			ledger.check(1, 1, &Answer::Text("abc".to_owned())).await,
+			Err(Rejection::KnownWrong)
+		);
+		assert_eq!(ledger.check(1, 1, &Answer::Text("abd".to_owned())).await, Ok(()));
+	}
+
+	#[tokio::test]
+	async fn refuses_answers_at_or_above_the_lowest_too_high() {
+		let (_directory, ledger) = ledger_with(&[("500", Verdict::TooHigh), ("300", Verdict::TooHigh)]).await;
+
+		assert_eq!(ledger.check(1, 1, &Answer::Number(300)).await, Err(Rejection::KnownWrong));
+		assert_eq!(ledger.check(1, 1, &Answer::Number(301)).await, Err(Rejection::AtOrAbove(300)));
+		assert_eq!(ledger.check(1, 1, &Answer::Number(400)).await, Err(Rejection::AtOrAbove(300)));
+		assert_eq!(ledger.check(1, 1, &Answer::Number(299)).await, Ok(()));
+	}
+
+	#[tokio::test]
+	async fn refuses_answers_at_or_below_the_highest_too_low() {
+		let (_directory, ledger) = ledger_with(&[("100", Verdict::TooLow), ("200", Verdict::TooLow)]).await;
+
+		assert_eq!(ledger.check(1, 1, &Answer::Number(199)).await, Err(Rejection::AtOrBelow(200)));
+		assert_eq!(ledger.check(1, 1, &Answer::Number(150)).await, Err(Rejection::AtOrBelow(200)));
+		assert_eq!(ledger.check(1, 1, &Answer::Number(201)).await, Ok(()));
+	}
+
+	#[tokio::test]
+	async fn skips_the_bounds_for_answers_that_are_not_numbers() {
+		let (_directory, ledger) = ledger_with(&[("300", Verdict::TooHigh), ("100", Verdict::TooLow)]).await;
+
+		assert_eq!(ledger.check(1, 1, &Answer::Text("ABCDEF".to_owned())).await, Ok(()));
+	}
+
+	#[tokio::test]
+	async fn refuses_everything_once_solved() {
+		let (_directory, ledger) = ledger_with(&[("100", Verdict::TooLow), ("150", Verdict::Correct)]).await;
+
+		let solved = Err(Rejection::AlreadySolved("150".to_owned()));
+		assert_eq!(ledger.check(1, 1, &Answer::Number(150)).await, solved);
+		assert_eq!(ledger.check(1, 1, &Answer::Number(120)).await, solved);
+		assert_eq!(ledger.check(1, 1, &Answer::Text("other".to_owned())).await, solved);
+	}
+}
@@ -1,4 +1,9 @@
-mod utils;
+mod bench;
+mod registry;
+mod solution;
+mod summary;
+mod table;
+pub mod utils;
 
 mod day_1;
 mod day_2;
@@ -9,6 +14,12 @@ mod day_6;
 mod day_7;
 mod day_8;
 
+pub use bench::*;
+pub use registry::*;
+pub use solution::*;
+pub use summary::*;
+pub use table::*;
+
 pub use day_1::*;
 pub use day_2::*;
 pub use day_3::*;
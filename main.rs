@@ -1,72 +1,815 @@
+mod account;
+mod answers;
 mod build_bin;
 mod cache;
-mod utils;
+mod client;
+mod config;
+mod fixtures;
+mod guard;
+mod hash;
+mod history;
+mod ledger;
+mod puzzle;
+mod release;
+mod report;
+mod scaffold;
+mod selection;
+mod submit;
+mod throttle;
 
 use advent_of_code_2023::*;
-use build_bin::build_bin;
+use account::Account;
+use answers::{AnswerBook, DayAnswers};
+use build_bin::{build_bin, build_bundle};
 use cache::InputsCache;
-use clap::Parser;
-use reqwest::{Client, Method};
-use std::{env, time::Instant};
+use clap::{Args, Parser, Subcommand};
+use client::{AdventClient, FetchError};
+use config::{load_dotenv, Config, ThrottleMode};
+use fixtures::Fixtures;
+use history::{current_commit, BenchHistory, BenchRecord, Change};
+use hash::stable_hash;
+use guard::{run_guarded, run_pooled, silence_panics, Outcome};
+use ledger::GuessLedger;
+use puzzle::Puzzle;
+use report::{json_error, json_lines, Format};
+use release::{wait_for_release, Clock, SystemClock};
+use scaffold::scaffold_day;
+use selection::DaySelection;
+use submit::Verdict;
+use throttle::Throttle;
+use tokio::{
+	fs::{read_dir, read_to_string},
+	io::{stdin, AsyncReadExt},
+};
+use std::{
+	error::Error,
+	io::{self, IsTerminal},
+	panic,
+	path::{Path, PathBuf},
+	process,
+	time::{Duration, Instant},
+};
 
 #[derive(Parser)]
-#[command(bin_name = ".run")]
+#[command(bin_name = ".run", subcommand_negates_reqs = true)]
 struct ProgramArgs {
-	day: u64,
+	#[command(subcommand)]
+	command: Option<Command>,
+
+	/// A day, a range of days like 1..8, or all. Running more than one day only uses cached inputs.
+	#[arg(required = true)]
+	day: Option<DaySelection>,
+
+	#[command(flatten)]
+	run: RunArgs,
+
+	/// Where to reach Advent of Code, defaulting to https://adventofcode.com
+	#[arg(long, global = true)]
+	base_url: Option<String>,
+
+	/// The event year, defaulting to 2023
+	#[arg(long, global = true)]
+	year: Option<u64>,
+
+	/// Where to cache inputs, instead of $XDG_CACHE_HOME/advent_of_code_{year}
+	#[arg(long, global = true)]
+	cache_dir: Option<PathBuf>,
+
+	/// Use the session of an account from the config, instead of ADVENT_OF_CODE_SESSION
+	#[arg(long, global = true)]
+	account: Option<String>,
+}
 
+#[derive(Args)]
+struct RunArgs {
+	/// Build an optimized binary with the input embedded, or with several days, one binary that runs them all
 	#[arg(long)]
 	build: bool,
+
+	/// If the day hasn't unlocked yet, count down until it does, then fetch the input and run it
+	#[arg(long)]
+	wait: bool,
+
+	/// Run on this file instead of the cached input, or on stdin if it's -
+	#[arg(long, conflicts_with = "wait")]
+	input: Option<PathBuf>,
+
+	/// How to print the answers and timings
+	#[arg(long, value_enum, default_value_t = Format::Human)]
+	format: Format,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Submit the computed answer for one part of a day
+	Submit {
+		day: u64,
+
+		#[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+		part: u8,
+	},
+
+	/// Print the puzzle description for a day
+	Read { day: u64 },
+
+	/// Run a day against the examples from its puzzle description
+	Check {
+		day: u64,
+
+		/// Fetch the puzzle page again, such as once part 2 has been unlocked, instead of using the cached one
+		#[arg(long)]
+		refetch: bool,
+	},
+
+	/// Run a day against every input in a directory, to check that it works for more than one input
+	Inputs {
+		day: u64,
+
+		directory: PathBuf,
+
+		/// How many seconds each part gets before it is considered stuck
+		#[arg(long, default_value_t = 10)]
+		timeout: u64,
+	},
+
+	/// Create and register a new day, with a solution named after the puzzle
+	New { day: u64, title: String },
+
+	/// Rerun every day with a recorded answer, and fail if any answer has changed
+	Verify {
+		/// Record the current answers instead, including those of days with a cached input but no recorded answers
+		#[arg(long)]
+		accept: bool,
+	},
+
+	/// Time parsing and each part of a day over many runs
+	Bench {
+		day: u64,
+
+		/// How many timed runs to make
+		#[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+		iterations: u32,
+
+		/// How many untimed runs to make first
+		#[arg(long, default_value_t = 10)]
+		warmup: u32,
+
+		/// Name this run, so it can be compared against later
+		#[arg(long)]
+		name: Option<String>,
+
+		/// Compare against the previous run, or the latest run with this name or commit
+		#[arg(long)]
+		compare: Option<Option<String>>,
+	},
+
+	/// Manage the cached inputs
+	Cache {
+		#[command(subcommand)]
+		command: CacheCommand,
+	},
+
+	/// Work with the session used to reach Advent of Code
+	Auth {
+		#[command(subcommand)]
+		command: AuthCommand,
+	},
+}
+
+#[derive(Subcommand)]
+enum AuthCommand {
+	/// Check that the session is still valid, and show who it belongs to
+	Check,
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+	/// List the days with a cached input
+	List,
+
+	/// Print the cached input for a day
+	Show { day: u64 },
+
+	/// Remove the cached input and puzzle page for a day
+	Remove { day: u64 },
+
+	/// Remove every cached input and puzzle page, keeping the record of submitted guesses
+	Clear,
+
+	/// Copy every cached input into a directory
+	Export { directory: PathBuf },
+
+	/// Cache every day_{day}.txt from a directory
+	Import { directory: PathBuf },
 }
 
 #[tokio::main]
 async fn main() {
 	let args = ProgramArgs::parse();
-	let day = args.day;
-	let session = env::var("ADVENT_OF_CODE_SESSION").expect("Expected an ADVENT_OF_CODE_SESSION");
-	let cache = InputsCache::new().await;
 
-	let input = match cache.get(day).await {
-		Some(input) => input,
-		None => {
-			let input = fetch_input(day, session).await;
-			cache.set(day, input.clone()).await;
+	let config = Config::load(load_dotenv().await).await.unwrap_or_else(|error| {
+		eprintln!("{error}");
+		process::exit(1)
+	});
+	let account = config.account(args.account.as_deref());
+	let base_url = args.base_url.unwrap_or_else(|| config.base_url());
+	let year = args.year.unwrap_or_else(|| {
+		config.year().unwrap_or_else(|error| {
+			eprintln!("{error}");
+			process::exit(1)
+		})
+	});
+	let cache = InputsCache::new(year, args.cache_dir, &account).await.unwrap_or_else(|error| {
+		eprintln!("Couldn't open the cache: {error}");
+		process::exit(1)
+	});
+	let throttle = Throttle::new(&cache, config.request_interval(), config.throttle() == ThrottleMode::Refuse);
+	let client = AdventClient::new(&base_url, year, account.session.clone(), &config.user_agent(), throttle);
 
-			input
-		}
+	let result = match args.command {
+		Some(Command::Submit { day, part }) => submit(day, part, &cache, &client, &GuessLedger::new(&cache), &AnswerBook::new(&cache)).await,
+		Some(Command::Read { day }) => read(day, &cache, &client).await,
+		Some(Command::Check { day, refetch }) => check(day, refetch, &cache, &client).await,
+		Some(Command::Inputs { day, directory, timeout }) => run_inputs(day, &directory, Duration::from_secs(timeout)).await,
+		Some(Command::New { day, title }) => new_day(day, &title, &cache).await,
+		Some(Command::Verify { accept }) => verify(accept, &cache, &AnswerBook::new(&cache)).await,
+		Some(Command::Bench {
+			day,
+			iterations,
+			warmup,
+			name,
+			compare,
+		}) => bench(day, iterations, warmup, name, compare, &cache, &client).await,
+		Some(Command::Cache { command }) => manage_cache(command, &cache, &account).await,
+		Some(Command::Auth { command: AuthCommand::Check }) => check_auth(&client, &cache, &account).await,
+		None => match args.day.unwrap() {
+			DaySelection::Day(day) => run(day, year, args.run, &cache, &client).await,
+			_ if args.run.wait || args.run.input.is_some() => Err("--wait and --input only work with a single day".into()),
+			selection if args.run.build => bundle(&selection, &cache).await,
+			selection => run_all(&selection, args.run.format, &cache).await,
+		},
+	};
+
+	if let Err(error) = result {
+		eprintln!("{error}");
+		process::exit(1);
+	}
+}
+
+async fn run(day: u64, year: u64, args: RunArgs, cache: &InputsCache, client: &AdventClient) -> Result<(), Box<dyn Error>> {
+	let registered = registered_day(day)?;
+	let input = match args.input {
+		Some(path) => read_input(&path).await.map_err(|error| format!("Couldn't read {}: {error}", path.display()))?,
+		None if args.wait => wait_for_input(day, year, &SystemClock, cache, client).await?,
+		None => get_input(day, cache, client).await?,
 	};
 
 	if args.build {
-		build_bin(day, input).await
+		build_bin(day, input).await?;
 	} else {
-		let start = Instant::now();
+		let input_hash = stable_hash(&input);
+		let solved = (registered.solve)(input);
 
-		match args.day {
-			1 => trebuchet(input),
-			2 => cube_conundrum(input),
-			3 => gear_ratios(input),
-			4 => scratchcards(input),
-			5 => seeds(input),
-			6 => wait_for_it(input),
-			7 => camel_cards(input),
-			8 => haunted_wasteland(input),
-			_ => println!("Unknown day"),
+		if args.format == Format::Json {
+			for line in json_lines(day, &input_hash, &solved) {
+				println!("{line}");
+			}
+
+			return Ok(());
 		}
 
-		let ms = start.elapsed().as_millis();
-		println!("Executed day {day} in {ms}ms");
+		let [part_one, part_two] = &solved.answers;
+		println!("part_one={part_one} part_two={part_two}");
+		println!(
+			"Executed day {day} in {} (parse {}, solve {})",
+			format_duration(solved.parse_time + solved.solve_time()),
+			format_duration(solved.parse_time),
+			format_duration(solved.solve_time())
+		);
 	}
+
+	Ok(())
 }
 
-async fn fetch_input(day: u64, session: String) -> String {
-	println!("Fetching inputs for day {day}...");
+// An input given by hand is never cached, so it can't be mistaken for the account's own
+async fn read_input(path: &Path) -> io::Result<String> {
+	if path == Path::new("-") {
+		let mut input = String::new();
+		stdin().read_to_string(&mut input).await?;
+
+		Ok(input)
+	} else {
+		read_to_string(path).await
+	}
+}
+
+// Only genuine inputs are cached, so a failed fetch is retried next time instead of being read back from the cache
+async fn get_input(day: u64, cache: &InputsCache, client: &AdventClient) -> Result<String, FetchError> {
+	if let Some(input) = cache.get(day).await {
+		return Ok(input);
+	}
+
+	let input = client.fetch_input(day).await?;
+	cache.set(day, input.clone()).await.map_err(FetchError::Cache)?;
+
+	Ok(input)
+}
+
+// The server can lag slightly behind the release instant, so a puzzle that isn't found yet is retried a few times
+async fn wait_for_input<C: Clock>(day: u64, year: u64, clock: &C, cache: &InputsCache, client: &AdventClient) -> Result<String, FetchError> {
+	if let Some(input) = cache.get(day).await {
+		return Ok(input);
+	}
+
+	wait_for_release(clock, year, day).await;
+
+	let mut attempts = 0;
+
+	loop {
+		match get_input(day, cache, client).await {
+			Err(FetchError::NotFound) if attempts < 5 => {
+				attempts += 1;
+				clock.sleep(Duration::from_secs(2)).await;
+			}
+			result => return result,
+		}
+	}
+}
+
+// A cached page is refetched until it includes part two, which only appears once part one has been solved
+async fn get_puzzle(day: u64, cache: &InputsCache, client: &AdventClient) -> Result<Puzzle, FetchError> {
+	if let Some(html) = cache.get_page(day).await {
+		let puzzle = Puzzle::new(html);
+
+		if puzzle.has_part_two() {
+			return Ok(puzzle);
+		}
+	}
+
+	fetch_puzzle(day, cache, client).await
+}
+
+async fn fetch_puzzle(day: u64, cache: &InputsCache, client: &AdventClient) -> Result<Puzzle, FetchError> {
+	let html = client.fetch_page(day).await?;
+	cache.set_page(day, html.clone()).await.map_err(FetchError::Cache)?;
+
+	Ok(Puzzle::new(html))
+}
+
+async fn read(day: u64, cache: &InputsCache, client: &AdventClient) -> Result<(), Box<dyn Error>> {
+	let puzzle = get_puzzle(day, cache, client).await?;
+	// Escapes would only clutter the text when it's redirected to a file or piped
+	println!("{}", puzzle.render(io::stdout().is_terminal()));
+
+	Ok(())
+}
+
+fn registered_day(day: u64) -> Result<&'static Day, Box<dyn Error>> {
+	find_day(day).ok_or_else(|| format!("Unknown day {day}").into())
+}
+
+// Examples are extracted from the puzzle page the first time a day is checked, and read from the fixtures after that. A cached
+// page is used even without part 2, so that checking a day doesn't make a request every time until part 2 unlocks.
+async fn check(day: u64, refetch: bool, cache: &InputsCache, client: &AdventClient) -> Result<(), Box<dyn Error>> {
+	let registered = registered_day(day)?;
+	let fixtures = Fixtures::new();
+	let mut examples = fixtures.get(day).await;
+
+	if examples.len() < 2 {
+		let puzzle = match cache.get_page(day).await {
+			Some(html) if !refetch => Ok(Puzzle::new(html)),
+			_ => fetch_puzzle(day, cache, client).await,
+		};
+
+		match puzzle {
+			Ok(puzzle) if puzzle.examples().len() > examples.len() => {
+				examples = puzzle.examples();
+				fixtures.set(day, &examples).await;
+			}
+			Ok(_) => (),
+			Err(error) if !examples.is_empty() => println!("Couldn't look for more examples: {error}"),
+			Err(error) => return Err(error.into()),
+		}
+	}
+
+	if examples.is_empty() {
+		println!("Couldn't find any examples for day {day}");
+		return Ok(());
+	}
+
+	let examples_count = examples.len();
+
+	for example in examples {
+		let part = example.part;
+		let result = panic::catch_unwind(|| (registered.solve_part)(example.input, part));
+
+		match result {
+			Ok(answer) if answer.to_string() == example.expected => println!("part {part}: PASS ({answer})"),
+			Ok(answer) => println!("part {part}: FAIL (expected {}, got {answer})", example.expected),
+			Err(_) => println!("part {part}: FAIL (panicked)"),
+		}
+	}
+
+	if examples_count < 2 {
+		println!("Part 2 has no example yet. Once it has been unlocked, run `.run check {day} --refetch` to look again.");
+	}
+
+	Ok(())
+}
+
+async fn submit(
+	day: u64,
+	part: u8,
+	cache: &InputsCache,
+	client: &AdventClient,
+	ledger: &GuessLedger,
+	answers: &AnswerBook,
+) -> Result<(), Box<dyn Error>> {
+	let registered = registered_day(day)?;
+	let input = get_input(day, cache, client).await?;
+
+	let answer = match (registered.solve_part)(input, part) {
+		Answer::Unsolved => {
+			println!("Day {day} part {part} is unsolved, so there is nothing to submit");
+			return Ok(());
+		}
+		answer => answer,
+	};
+
+	if let Err(rejection) = ledger.check(day, part, &answer).await {
+		println!("Not submitting {answer}: {rejection}");
+		return Ok(());
+	}
+
+	match client.submit_answer(day, part, &answer).await? {
+		Some(verdict) => {
+			ledger.record(day, part, &answer, &verdict).await;
+			println!("{verdict}");
+
+			if verdict == Verdict::Correct {
+				answers.record(day, part, &answer).await?;
+			}
+		}
+		None => println!("Couldn't understand the response to the submission"),
+	}
+
+	Ok(())
+}
+
+// Only cached inputs are used, so that running many days never sends a burst of requests. The days all run at once, and
+// their results are collected in order.
+async fn run_all(selection: &DaySelection, format: Format, cache: &InputsCache) -> Result<(), Box<dyn Error>> {
+	silence_panics();
+
+	let mut inputs = Vec::new();
+
+	for registered in DAYS.iter().filter(|registered| selection.contains(registered.number)) {
+		inputs.push((registered, cache.get(registered.number).await));
+	}
+
+	let start = Instant::now();
+	let jobs = inputs
+		.into_iter()
+		.map(|(registered, input)| {
+			let solve = registered.solve;
+			let job = input.map(|input| (stable_hash(&input), tokio::spawn(run_pooled(move || solve(input)))));
+
+			(registered, job)
+		})
+		.collect::<Vec<_>>();
+
+	let mut summary = Summary::default();
+
+	for (registered, job) in jobs {
+		let day = registered.number;
+
+		let Some((input_hash, job)) = job else {
+			match format {
+				Format::Human => summary.failed(registered, "missing input"),
+				Format::Json => println!("{}", json_error(day, "missing input")),
+			}
+
+			continue;
+		};
+
+		match (job.await?, format) {
+			(Outcome::Finished(solved), Format::Human) => summary.solved(registered, &solved),
+			(Outcome::Finished(solved), Format::Json) => {
+				for line in json_lines(day, &input_hash, &solved) {
+					println!("{line}");
+				}
+			}
+			(outcome, Format::Human) => summary.failed(registered, &outcome.map(|_| "").to_string()),
+			(outcome, Format::Json) => println!("{}", json_error(day, &outcome.map(|_| "").to_string())),
+		}
+	}
+
+	if format == Format::Human {
+		summary.print(start.elapsed());
+	}
+
+	Ok(())
+}
+
+// The puzzle page is only used if it's already cached, so that scaffolding a day never waits on the network
+async fn new_day(day: u64, title: &str, cache: &InputsCache) -> Result<(), Box<dyn Error>> {
+	let name = scaffold_day(day, title).await?;
+	println!("Created day_{day}.rs with {name}, and registered it");
+
+	let Some(html) = cache.get_page(day).await else {
+		println!("No examples were added to the fixtures, since the puzzle page isn't cached. `.run check {day}` will fetch them.");
+		return Ok(());
+	};
+
+	let examples = Puzzle::new(html).examples();
+
+	if examples.is_empty() {
+		println!("No examples were added to the fixtures, since none were found in the cached puzzle page");
+	} else {
+		Fixtures::new().set(day, &examples).await;
+		println!("Added {} examples to the fixtures", examples.len());
+	}
+
+	Ok(())
+}
+
+// Days without a cached input are left out, rather than fetched, since the point of the bundle is to run offline
+async fn bundle(selection: &DaySelection, cache: &InputsCache) -> Result<(), Box<dyn Error>> {
+	let mut inputs = Vec::new();
+
+	for registered in DAYS.iter().filter(|registered| selection.contains(registered.number)) {
+		match cache.get(registered.number).await {
+			Some(input) => inputs.push((registered.number, input)),
+			None => println!("Leaving out day {}, which has no cached input", registered.number),
+		}
+	}
+
+	if inputs.is_empty() {
+		return Err("None of the selected days have a cached input to bundle".into());
+	}
+
+	build_bundle(inputs).await
+}
+
+// Every day with a recorded answer is rerun against its cached input. An answer that has changed, or a day that can't be run,
+// fails the whole verification.
+// Accepting also covers days that have a cached input but nothing recorded, so that a lost or new answers.toml can be
+// filled in from the current solutions
+async fn verify(accept: bool, cache: &InputsCache, answers: &AnswerBook) -> Result<(), Box<dyn Error>> {
+	let mut days = answers.days().await?;
+
+	if accept {
+		for registered in DAYS {
+			if !days.iter().any(|(day, _)| *day == registered.number) && cache.get(registered.number).await.is_some() {
+				days.push((registered.number, DayAnswers::default()));
+			}
+		}
+
+		days.sort_by_key(|(day, _)| *day);
+	}
+
+	if days.is_empty() {
+		println!("No answers have been recorded yet. They are recorded when a submission is correct.");
+		return Ok(());
+	}
+
+	silence_panics();
+
+	let mut rows = Vec::new();
+	let mut failures = 0;
+
+	for (day, recorded) in days {
+		let result = match (find_day(day), cache.get(day).await) {
+			(None, _) => Err("unknown day".to_owned()),
+			(_, None) => Err("missing input".to_owned()),
+			(Some(registered), Some(input)) => match run_pooled(move || (registered.solve)(input)).await {
+				Outcome::Finished(solved) => Ok(solved.answers),
+				outcome => Err(outcome.map(|_| "").to_string()),
+			},
+		};
+
+		let current = match result {
+			Ok(current) => current,
+			Err(error) => {
+				failures += 1;
+				rows.push(vec![day.to_string(), error]);
+				continue;
+			}
+		};
+
+		let mut row = vec![day.to_string()];
+
+		for (part, answer) in [1, 2].into_iter().zip(current) {
+			row.push(match recorded.part(part) {
+				Some(expected) if *expected == answer.to_string() => format!("{answer} ok"),
+				_ if accept && answer == Answer::Unsolved => answer.to_string(),
+				Some(expected) if accept => {
+					answers.record(day, part, &answer).await?;
+					format!("{answer} accepted, was {expected}")
+				}
+				None if accept => {
+					answers.record(day, part, &answer).await?;
+					format!("{answer} accepted")
+				}
+				Some(expected) => {
+					failures += 1;
+					format!("CHANGED: expected {expected}, got {answer}")
+				}
+				None => format!("{answer} (not recorded)"),
+			});
+		}
+
+		rows.push(row);
+	}
+
+	print_table(&["day", "part 1", "part 2"], &rows);
+
+	if failures > 0 {
+		return Err(format!("{failures} recorded answers couldn't be verified").into());
+	}
+
+	println!();
+
+	if accept {
+		println!("The current answers have been recorded");
+	} else {
+		println!("Every recorded answer still matches");
+	}
+
+	Ok(())
+}
+
+// Every run is added to the history after it has been compared, so that it's never compared against itself
+async fn bench(
+	day: u64,
+	iterations: u32,
+	warmup: u32,
+	name: Option<String>,
+	compare: Option<Option<String>>,
+	cache: &InputsCache,
+	client: &AdventClient,
+) -> Result<(), Box<dyn Error>> {
+	let registered = registered_day(day)?;
+
+	if cfg!(debug_assertions) {
+		println!("This is a debug build, so the timings will be much slower than they should be. Run with --release instead.");
+	}
+
+	let input = get_input(day, cache, client).await?;
+	let input_hash = stable_hash(&input);
+	let measurements = (registered.measure)(input, warmup, iterations);
+	let commit = current_commit().await;
+
+	let records = [
+		("parse", &measurements.parse),
+		("part 1", &measurements.part_one),
+		("part 2", &measurements.part_two),
+	]
+	.into_iter()
+	.map(|(stage, samples)| BenchRecord::new(day, stage, samples, &input_hash, &commit, name.as_deref()))
+	.collect::<Vec<_>>();
+
+	let rows = records
+		.iter()
+		.map(|record| {
+			vec![
+				record.stage.clone(),
+				format!("{:.0}", record.min),
+				format!("{:.0}", record.median),
+				format!("{:.0}", record.mean),
+				format!("{:.0}", record.stddev),
+			]
+		})
+		.collect::<Vec<_>>();
+
+	println!("Day {day} at {commit}, {iterations} runs after {warmup} warm-up runs, in nanoseconds:");
+	print_table(&["", "min", "median", "mean", "stddev"], &rows);
+
+	let history = BenchHistory::new(cache);
+
+	if let Some(baseline) = compare {
+		let mut rows = Vec::new();
+
+		for record in &records {
+			let Some(previous) = history.baseline(record, baseline.as_deref()).await else {
+				rows.push(vec![record.stage.clone(), "no baseline".to_owned()]);
+				continue;
+			};
+
+			let change = match record.compare(&previous) {
+				Change::Slower => "SLOWER",
+				Change::Faster => "faster",
+				Change::Insignificant => "no significant change",
+			};
+
+			rows.push(vec![
+				record.stage.clone(),
+				previous.name.clone().unwrap_or(previous.commit.clone()),
+				format!("{:.0}", previous.mean),
+				format!("{:.0}", record.mean),
+				format!("{:+.1}%", (record.mean - previous.mean) / previous.mean * 100.0),
+				change.to_owned(),
+			]);
+		}
+
+		println!();
+		print_table(&["", "baseline", "baseline mean", "mean", "change", ""], &rows);
+	}
+
+	history.append(&records).await;
+
+	Ok(())
+}
+
+async fn run_inputs(day: u64, directory: &Path, timeout: Duration) -> Result<(), Box<dyn Error>> {
+	let solve_part = registered_day(day)?.solve_part;
+	let mut paths = Vec::new();
+	let mut entries = read_dir(directory).await?;
+
+	// Hidden files like .DS_Store are never inputs
+	while let Some(entry) = entries.next_entry().await? {
+		if entry.file_type().await?.is_file() && !entry.file_name().to_string_lossy().starts_with('.') {
+			paths.push(entry.path());
+		}
+	}
+
+	paths.sort();
+	silence_panics();
+
+	let mut rows = Vec::new();
+
+	for path in paths {
+		let mut row = vec![path.file_name().unwrap().to_string_lossy().into_owned()];
+
+		let input = match read_to_string(&path).await {
+			Ok(input) => input,
+			Err(error) => {
+				row.push(format!("couldn't read: {error}"));
+				rows.push(row);
+				continue;
+			}
+		};
+
+		for part in [1, 2] {
+			let input = input.clone();
+			let outcome = run_guarded(Some(timeout), move || solve_part(input, part));
+
+			row.push(outcome.to_string());
+		}
+
+		rows.push(row);
+	}
+
+	print_table(&["input", "part 1", "part 2"], &rows);
+
+	Ok(())
+}
+
+async fn manage_cache(command: CacheCommand, cache: &InputsCache, account: &Account) -> Result<(), Box<dyn Error>> {
+	match command {
+		CacheCommand::List => {
+			println!("Inputs cached for the {} account in {}:", account.name, cache.path().display());
+
+			for day in cache.days().await {
+				let lines = cache.get(day).await.unwrap_or_default().lines().count();
+				println!("day {day}: {lines} lines");
+			}
+		}
+		CacheCommand::Show { day } => match cache.get(day).await {
+			Some(input) => print!("{input}"),
+			None => return Err(format!("No input is cached for day {day}").into()),
+		},
+		CacheCommand::Remove { day } => match cache.remove(day).await {
+			true => println!("Removed the input for day {day}"),
+			false => return Err(format!("No input is cached for day {day}").into()),
+		},
+		CacheCommand::Clear => {
+			let days = cache.clear().await;
+			println!("Removed {} cached inputs", days.len());
+		}
+		CacheCommand::Export { directory } => {
+			let days = cache.export(&directory).await?;
+			println!("Exported {} inputs to {}", days.len(), directory.display());
+		}
+		CacheCommand::Import { directory } => {
+			let days = cache
+				.import(&directory)
+				.await
+				.map_err(|error| format!("Couldn't import the inputs in {}: {error}", directory.display()))?;
+			println!("Imported {} inputs from {}", days.len(), directory.display());
+		}
+	}
+
+	Ok(())
+}
+
+async fn check_auth(client: &AdventClient, cache: &InputsCache, account: &Account) -> Result<(), Box<dyn Error>> {
+	let user = client.fetch_user().await?;
+	println!("The session for the {} account is logged in as {user}", account.name);
+
+	if cache.confirm_user(&user).await? > 0 {
+		println!("Carried over what was cached for an earlier session of {user}");
+	}
 
-	Client::new()
-		.request(Method::GET, format!("https://adventofcode.com/2023/day/{day}/input"))
-		.header("Cookie", format!("session={session}"))
-		.send()
-		.await
-		.unwrap()
-		.text()
-		.await
-		.unwrap()
+	Ok(())
 }
@@ -0,0 +1,232 @@
+use regex::{Captures, Regex};
+
+use crate::fixtures::Example;
+
+const BOLD: &str = "\x1b[1m";
+const NORMAL: &str = "\x1b[22m";
+const CYAN: &str = "\x1b[36m";
+const DEFAULT_COLOR: &str = "\x1b[39m";
+
+/// A puzzle page, as served from `/day/{day}`
+pub struct Puzzle {
+	html: String,
+}
+
+impl Puzzle {
+	pub fn new(html: String) -> Puzzle {
+		Puzzle { html }
+	}
+
+	/// The raw HTML of each part's description. The second part is only present once it has been unlocked.
+	pub fn articles(&self) -> Vec<String> {
+		Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#)
+			.unwrap()
+			.captures_iter(&self.html)
+			.map(|captures| captures.get(1).unwrap().as_str().to_owned())
+			.collect()
+	}
+
+	pub fn has_part_two(&self) -> bool {
+		self.articles().len() > 1
+	}
+
+	/// Each part's example is the first code block in its description, falling back to part one's when part two has none.
+	/// The expected answer is the last highlighted code in the description.
+	pub fn examples(&self) -> Vec<Example> {
+		let block_regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+		let answer_regex = Regex::new(r"<code><em>([^<]*)</em></code>|<em><code>([^<]*)</code></em>").unwrap();
+		let tag_regex = Regex::new(r"<[^>]+>").unwrap();
+
+		let mut examples = Vec::new();
+		let mut last_input = None;
+
+		for (index, article) in self.articles().iter().enumerate() {
+			if let Some(captures) = block_regex.captures(article) {
+				let input = tag_regex.replace_all(captures.get(1).unwrap().as_str(), "");
+
+				last_input = Some(decode_entities(&input));
+			}
+
+			let expected = answer_regex
+				.captures_iter(article)
+				.last()
+				.map(|captures| captures.get(1).or(captures.get(2)).unwrap().as_str().to_owned());
+
+			if let (Some(input), Some(expected)) = (&last_input, expected) {
+				examples.push(Example {
+					part: index as u8 + 1,
+					input: input.clone(),
+					expected: decode_entities(&expected),
+				})
+			}
+		}
+
+		examples
+	}
+
+	/// The description as plain text, with emphasis and code highlighted by ANSI escapes when `styled`
+	pub fn render(&self, styled: bool) -> String {
+		self.articles()
+			.iter()
+			.map(|article| render_article(article, styled))
+			.collect::<Vec<String>>()
+			.join("\n\n")
+	}
+}
+
+fn render_article(html: &str, styled: bool) -> String {
+	let (bold, normal, cyan, default_color) = if styled {
+		(BOLD, NORMAL, CYAN, DEFAULT_COLOR)
+	} else {
+		("", "", "", "")
+	};
+
+	let text = Regex::new(r"(?s)<h2[^>]*>(.*?)</h2>")
+		.unwrap()
+		.replace_all(html, format!("{bold}$1{normal}\n\n"));
+
+	let text = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap().replace_all(&text, |captures: &Captures| {
+		let lines = captures
+			.get(1)
+			.unwrap()
+			.as_str()
+			.trim_end()
+			.lines()
+			.map(|line| format!("    {line}"))
+			.collect::<Vec<String>>();
+
+		format!("{cyan}{}{default_color}\n\n", lines.join("\n"))
+	});
+
+	let text = Regex::new(r"<em[^>]*>").unwrap().replace_all(&text, bold);
+	let text = text.replace("</em>", normal);
+	let text = Regex::new(r"<code[^>]*>").unwrap().replace_all(&text, cyan);
+	let text = text.replace("</code>", default_color);
+	let text = text.replace("<li>", "  - ").replace("</p>", "\n\n");
+	let text = Regex::new(r"<[^>]+>").unwrap().replace_all(&text, "");
+	let text = decode_entities(&text);
+
+	Regex::new(r"\n{3,}").unwrap().replace_all(text.trim(), "\n\n").into_owned()
+}
+
+pub fn decode_entities(text: &str) -> String {
+	text.replace("&lt;", "<")
+		.replace("&gt;", ">")
+		.replace("&quot;", "\"")
+		.replace("&#39;", "'")
+		.replace("&apos;", "'")
+		.replace("&nbsp;", " ")
+		.replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// Trimmed from the pages for days 1, 2 and 19
+	const DAY_1_PART_ONE: &str = r#"<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+<p>Consider your entire calibration document. <em>What is the sum of all of the calibration values?</em></p>
+</article>"#;
+
+	const DAY_1_PART_TWO: &str = r#"<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Your calculation isn't quite right. It looks like some of the digits are actually <em>spelled out with letters</em>: <code>one</code>, <code>two</code>, <code>three</code>, <code>four</code>, <code>five</code>, <code>six</code>, <code>seven</code>, <code>eight</code>, and <code>nine</code> <em>also</em> count as valid "digits".</p>
+<pre><code>two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+</code></pre>
+<p>In this example, the calibration values are <code>29</code>, <code>83</code>, <code>13</code>, <code>24</code>, <code>42</code>, <code>14</code>, and <code>76</code>. Adding these together produces <code><em>281</em></code>.</p>
+</article>"#;
+
+	const DAY_2_PART_ONE: &str = r#"<article class="day-desc"><h2>--- Day 2: Cube Conundrum ---</h2><pre><code>Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+</code></pre>
+<p>If you add up the IDs of the games that would have been possible, you get <code><em>8</em></code>.</p>
+</article>"#;
+
+	const DAY_2_PART_TWO: &str = r#"<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Again consider the example games from earlier.</p>
+<p>Adding up these five powers produces the sum <code><em>2286</em></code>.</p>
+</article>"#;
+
+	const DAY_19_PART_ONE: &str = r#"<article class="day-desc"><h2>--- Day 19: Aplenty ---</h2><pre><code>px{a&lt;2006:qkq,m&gt;2090:A,rfg}
+in{s&lt;1351:px,qqz}
+
+{x=787,m=2655,a=1222,s=2876}
+</code></pre>
+<p>Adding all of the ratings for <em>all</em> of the accepted parts gives the sum total of <code><em>19114</em></code>.</p>
+</article>"#;
+
+	fn page(articles: &[&str]) -> Puzzle {
+		Puzzle::new(format!("<html><body><main>{}</main></body></html>", articles.join("\n")))
+	}
+
+	#[test]
+	fn extracts_the_example_of_part_one() {
+		let examples = page(&[DAY_1_PART_ONE]).examples();
+
+		assert_eq!(examples.len(), 1);
+		assert_eq!(examples[0].part, 1);
+		assert_eq!(examples[0].input, "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n");
+		assert_eq!(examples[0].expected, "142");
+	}
+
+	#[test]
+	fn extracts_the_example_of_each_part() {
+		let examples = page(&[DAY_1_PART_ONE, DAY_1_PART_TWO]).examples();
+
+		assert_eq!(examples.len(), 2);
+		assert_eq!(examples[1].part, 2);
+		assert!(examples[1].input.starts_with("two1nine\n"));
+		assert_eq!(examples[1].expected, "281");
+	}
+
+	#[test]
+	fn falls_back_to_the_example_of_part_one() {
+		let examples = page(&[DAY_2_PART_ONE, DAY_2_PART_TWO]).examples();
+
+		assert_eq!(examples.len(), 2);
+		assert_eq!(examples[1].input, examples[0].input);
+		assert_eq!(examples[0].expected, "8");
+		assert_eq!(examples[1].expected, "2286");
+	}
+
+	#[test]
+	fn decodes_entities_in_examples() {
+		let examples = page(&[DAY_19_PART_ONE]).examples();
+
+		assert!(examples[0].input.starts_with("px{a<2006:qkq,m>2090:A,rfg}\n"));
+		assert_eq!(examples[0].expected, "19114");
+	}
+
+	const SMALL_ARTICLE: &str = r#"<article class="day-desc"><h2>--- Day 9: Mirage ---</h2><p>Find the <em>next</em> value of <code>0 3 6</code> &amp; the others:</p>
+<pre><code>0 3 6
+1 3 6
+</code></pre>
+<ul><li>Sum them</li></ul>
+</article>"#;
+
+	#[test]
+	fn renders_plain_text() {
+		assert_eq!(
+			page(&[SMALL_ARTICLE]).render(false),
+			"--- Day 9: Mirage ---\n\nFind the next value of 0 3 6 & the others:\n\n    0 3 6\n    1 3 6\n\n  - Sum them"
+		);
+	}
+
+	#[test]
+	fn renders_styled_text() {
+		let rendered = page(&[SMALL_ARTICLE]).render(true);
+
+		assert!(rendered.starts_with("\x1b[1m--- Day 9: Mirage ---\x1b[22m\n\n"));
+		assert!(rendered.contains("the \x1b[1mnext\x1b[22m value of \x1b[36m0 3 6\x1b[39m &"));
+		assert!(rendered.contains("\x1b[36m    0 3 6\n    1 3 6\x1b[39m"));
+	}
+}
@@ -0,0 +1,39 @@
+// Every day's solution is in scope through the crate's re-exports
+use crate::*;
+
+/// A registered day, with entry points that run its solution on an input
+pub struct Day {
+	pub number: u64,
+	pub title: &'static str,
+	pub solve: fn(String) -> Solved,
+	pub solve_part: fn(String, u8) -> Answer,
+	pub measure: fn(String, u32, u32) -> Measurements,
+}
+
+impl Day {
+	pub const fn new<S: Solution>(number: u64, title: &'static str) -> Day {
+		Day {
+			number,
+			title,
+			solve: solve_timed::<S>,
+			solve_part: solve_part::<S>,
+			measure: measure::<S>,
+		}
+	}
+}
+
+/// Every day that has a solution, in order
+pub const DAYS: &[Day] = &[
+	Day::new::<Trebuchet>(1, "Trebuchet?!"),
+	Day::new::<CubeConundrum>(2, "Cube Conundrum"),
+	Day::new::<GearRatios>(3, "Gear Ratios"),
+	Day::new::<Scratchcards>(4, "Scratchcards"),
+	Day::new::<Seeds>(5, "If You Give A Seed A Fertilizer"),
+	Day::new::<WaitForIt>(6, "Wait For It"),
+	Day::new::<CamelCards>(7, "Camel Cards"),
+	Day::new::<HauntedWasteland>(8, "Haunted Wasteland"),
+];
+
+pub fn find_day(number: u64) -> Option<&'static Day> {
+	DAYS.iter().find(|day| day.number == number)
+}
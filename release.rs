@@ -0,0 +1,132 @@
+use std::{
+	future::Future,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// A source of time, so that waiting for a release can be tested without actually waiting
+pub trait Clock {
+	fn now(&self) -> SystemTime;
+
+	fn sleep(&self, duration: Duration) -> impl Future<Output = ()>;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+	fn now(&self) -> SystemTime {
+		SystemTime::now()
+	}
+
+	fn sleep(&self, duration: Duration) -> impl Future<Output = ()> {
+		tokio::time::sleep(duration)
+	}
+}
+
+/// Puzzles unlock at midnight US/Eastern, which is always 05:00 UTC because December is outside daylight saving time
+pub fn release_time(year: u64, day: u64) -> SystemTime {
+	let days = days_since_epoch(year as i64, 12, day as i64);
+
+	UNIX_EPOCH + Duration::from_secs(days as u64 * 86_400 + 5 * 3_600)
+}
+
+// Howard Hinnant's days_from_civil
+fn days_since_epoch(year: i64, month: i64, day: i64) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let year_of_era = year - era * 400;
+	let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+	era * 146_097 + day_of_era - 719_468
+}
+
+/// Shows a countdown until the puzzle unlocks. Returns straight away if it already has.
+pub async fn wait_for_release<C: Clock>(clock: &C, year: u64, day: u64) {
+	let release = release_time(year, day);
+	let mut waited = false;
+
+	while let Ok(remaining) = release.duration_since(clock.now()) {
+		if remaining.is_zero() {
+			break;
+		}
+
+		eprint!("\rDay {day} unlocks in {} ", format_countdown(remaining));
+
+		clock.sleep(remaining.min(Duration::from_secs(1))).await;
+		waited = true;
+	}
+
+	if waited {
+		eprintln!("\rDay {day} is unlocked!         ");
+	}
+}
+
+fn format_countdown(remaining: Duration) -> String {
+	let seconds = remaining.as_secs_f64().ceil() as u64;
+	let clock = format!("{:02}:{:02}:{:02}", seconds / 3_600 % 24, seconds / 60 % 60, seconds % 60);
+
+	match seconds / 86_400 {
+		0 => clock,
+		days => format!("{days}d {clock}"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::{cell::Cell, future};
+
+	/// A clock that only moves when something sleeps on it
+	struct TestClock {
+		now: Cell<SystemTime>,
+		sleeps: Cell<u32>,
+	}
+
+	impl TestClock {
+		fn at(now: SystemTime) -> TestClock {
+			TestClock {
+				now: Cell::new(now),
+				sleeps: Cell::new(0),
+			}
+		}
+	}
+
+	impl Clock for TestClock {
+		fn now(&self) -> SystemTime {
+			self.now.get()
+		}
+
+		fn sleep(&self, duration: Duration) -> impl Future<Output = ()> {
+			self.now.set(self.now.get() + duration);
+			self.sleeps.set(self.sleeps.get() + 1);
+
+			future::ready(())
+		}
+	}
+
+	#[test]
+	fn releases_at_midnight_eastern() {
+		// 2023-12-01T05:00:00Z
+		assert_eq!(release_time(2023, 1), UNIX_EPOCH + Duration::from_secs(1_701_406_800));
+		assert_eq!(release_time(2023, 25), release_time(2023, 1) + Duration::from_secs(24 * 86_400));
+	}
+
+	#[tokio::test]
+	async fn returns_at_once_after_the_release() {
+		let clock = TestClock::at(release_time(2023, 1) + Duration::from_secs(10));
+
+		wait_for_release(&clock, 2023, 1).await;
+
+		assert_eq!(clock.sleeps.get(), 0);
+		assert_eq!(clock.now(), release_time(2023, 1) + Duration::from_secs(10));
+	}
+
+	#[tokio::test]
+	async fn waits_until_exactly_the_release() {
+		let clock = TestClock::at(release_time(2023, 1) - Duration::from_millis(3_601_500));
+
+		wait_for_release(&clock, 2023, 1).await;
+
+		assert_eq!(clock.now(), release_time(2023, 1));
+	}
+}
@@ -0,0 +1,59 @@
+use advent_of_code_2023::{Answer, Solved};
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// How the results of running days are printed
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+	/// For reading in a terminal
+	Human,
+	/// One JSON object per line, for each part of each day
+	Json,
+}
+
+#[derive(Serialize)]
+struct PartResult<'a> {
+	day: u64,
+	part: usize,
+	answer: Value,
+	parse_ns: u64,
+	solve_ns: u64,
+	input_hash: &'a str,
+}
+
+/// One line for each part, with timings in nanoseconds. Both parts share the time spent parsing.
+pub fn json_lines(day: u64, input_hash: &str, solved: &Solved) -> Vec<String> {
+	solved
+		.answers
+		.iter()
+		.zip(solved.part_times)
+		.enumerate()
+		.map(|(index, (answer, time))| {
+			let result = PartResult {
+				day,
+				part: index + 1,
+				answer: answer_json(answer),
+				parse_ns: solved.parse_time.as_nanos() as u64,
+				solve_ns: time.as_nanos() as u64,
+				input_hash,
+			};
+
+			serde_json::to_string(&result).unwrap()
+		})
+		.collect()
+}
+
+/// The line for a day that couldn't be run
+pub fn json_error(day: u64, error: &str) -> String {
+	json!({ "day": day, "error": error }).to_string()
+}
+
+// Unsolved parts are null, so that they can't be mistaken for an answer
+fn answer_json(answer: &Answer) -> Value {
+	match answer {
+		Answer::Number(number) => json!(number),
+		Answer::Text(text) => json!(text),
+		Answer::Unsolved => Value::Null,
+	}
+}
@@ -0,0 +1,139 @@
+use std::{
+	error::Error,
+	path::{Path, PathBuf},
+};
+
+use regex::Regex;
+use tokio::fs::{read_to_string, try_exists, write};
+
+/// Creates `day_{day}.rs` in the repository, with a solution that parses its input into lines and solves neither part, then
+/// registers it in `lib.rs` and the registry of days. Returns the name of the solution.
+pub async fn scaffold_day(day: u64, title: &str) -> Result<String, Box<dyn Error>> {
+	let name = pascal_case(title).ok_or(format!("{title:?} can't be made into a type name"))?;
+	let source = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+	let file = source.join(format!("day_{day}.rs"));
+
+	if try_exists(&file).await? {
+		return Err(format!("{} already exists", file.display()).into());
+	}
+
+	let lib = read_to_string(source.join("lib.rs")).await?;
+	let registry = read_to_string(source.join("registry.rs")).await?;
+
+	if let Some(module) = defining_module(&source, &lib, &name).await? {
+		return Err(format!("{name} is already defined in {module}.rs, so day {day} needs a different title").into());
+	}
+
+	let lib = register(&lib, r"^mod day_(?P<day>\d+);$", day, |_| format!("mod day_{day};"))?;
+	let lib = register(&lib, r"^pub use day_(?P<day>\d+)::\*;$", day, |_| format!("pub use day_{day}::*;"))?;
+	let registry = register(&registry, r"^(\s*)Day::new::<\w+>\((?P<day>\d+), .*\),$", day, |captures| {
+		let indent = captures.get(1).unwrap().as_str();
+
+		format!("{indent}Day::new::<{name}>({day}, {title:?}),")
+	})?;
+
+	write(&file, template(&name)).await?;
+	write(source.join("lib.rs"), lib).await?;
+	write(source.join("registry.rs"), registry).await?;
+
+	Ok(name)
+}
+
+// Every module is glob re-exported from the crate root, so a solution named after any public item of another one would make
+// both ambiguous
+async fn defining_module(source: &Path, lib: &str, name: &str) -> Result<Option<String>, Box<dyn Error>> {
+	let item = Regex::new(&format!(r"(?m)^pub (?:struct|enum|trait|type|fn|const|static|mod) {name}\b")).unwrap();
+
+	for captures in Regex::new(r"(?m)^pub use (\w+)::\*;$").unwrap().captures_iter(lib) {
+		let module = &captures[1];
+		let text = read_to_string(source.join(format!("{module}.rs"))).await?;
+
+		if item.is_match(&text) {
+			return Ok(Some(module.to_owned()));
+		}
+	}
+
+	Ok(None)
+}
+
+// Adds a line among the ones matching `pattern`, keeping them in order of the day captured as `day`. The new line is made from
+// the captures of a neighbouring line, so it's formatted the same way.
+fn register<F>(text: &str, pattern: &str, day: u64, make_line: F) -> Result<String, Box<dyn Error>>
+where
+	F: Fn(&regex::Captures) -> String,
+{
+	let regex = Regex::new(pattern).unwrap();
+	let mut lines = text.lines().map(str::to_owned).collect::<Vec<String>>();
+	let mut insert_at = None;
+	let mut neighbour = None;
+
+	for (index, line) in lines.iter().enumerate() {
+		let Some(captures) = regex.captures(line) else {
+			continue;
+		};
+
+		let registered = captures.name("day").unwrap().as_str().parse::<u64>().unwrap();
+
+		if registered == day {
+			return Err(format!("Day {day} is already registered").into());
+		}
+
+		if registered < day || insert_at.is_none() {
+			insert_at = Some(if registered < day { index + 1 } else { index });
+			neighbour = Some(make_line(&captures));
+		}
+	}
+
+	match insert_at {
+		Some(index) => lines.insert(index, neighbour.unwrap()),
+		None => return Err(format!("Couldn't find where to register day {day}").into()),
+	}
+
+	Ok(lines.join("\n") + "\n")
+}
+
+fn pascal_case(name: &str) -> Option<String> {
+	let name = name
+		.split(|character: char| !character.is_alphanumeric())
+		.filter(|word| !word.is_empty())
+		.map(|word| {
+			let mut characters = word.chars();
+			let first = characters.next().unwrap().to_uppercase();
+
+			first.chain(characters).collect::<String>()
+		})
+		.collect::<String>();
+
+	match name.chars().next() {
+		Some(first) if first.is_alphabetic() => Some(name),
+		_ => None,
+	}
+}
+
+fn template(name: &str) -> String {
+	format!(
+		"use crate::solution::{{Answer, Solution}};
+use crate::utils::into_lines;
+
+pub struct {name} {{
+	pub lines: Vec<String>,
+}}
+
+impl Solution for {name} {{
+	fn parse(input: String) -> {name} {{
+		{name} {{
+			lines: into_lines(input),
+		}}
+	}}
+
+	fn part_one(&self) -> Answer {{
+		Answer::Unsolved
+	}}
+
+	fn part_two(&self) -> Answer {{
+		Answer::Unsolved
+	}}
+}}
+"
+	)
+}
@@ -0,0 +1,47 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+/// Which days to run: a single day, an inclusive range like `1..8`, or `all`
+#[derive(Clone)]
+pub enum DaySelection {
+	Day(u64),
+	Range(RangeInclusive<u64>),
+	All,
+}
+
+impl DaySelection {
+	pub fn contains(&self, day: u64) -> bool {
+		match self {
+			DaySelection::Day(selected) => *selected == day,
+			DaySelection::Range(range) => range.contains(&day),
+			DaySelection::All => true,
+		}
+	}
+}
+
+impl FromStr for DaySelection {
+	type Err = String;
+
+	fn from_str(text: &str) -> Result<DaySelection, String> {
+		if text == "all" {
+			return Ok(DaySelection::All);
+		}
+
+		match text.split_once("..") {
+			Some((start, end)) => {
+				let start = parse_day(start)?;
+				let end = parse_day(end.strip_prefix('=').unwrap_or(end))?;
+
+				if start > end {
+					return Err(format!("{text} is an empty range"));
+				}
+
+				Ok(DaySelection::Range(start..=end))
+			}
+			None => parse_day(text).map(DaySelection::Day),
+		}
+	}
+}
+
+fn parse_day(text: &str) -> Result<u64, String> {
+	text.parse::<u64>().map_err(|_| format!("{text:?} isn't a day, a range like 1..8, or all"))
+}
@@ -0,0 +1,86 @@
+use std::{
+	fmt::{self, Display},
+	time::{Duration, Instant},
+};
+
+/// The answer to a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+	Number(u64),
+	Text(String),
+	Unsolved,
+}
+
+impl Display for Answer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Answer::Number(number) => write!(f, "{number}"),
+			Answer::Text(text) => write!(f, "{text}"),
+			Answer::Unsolved => write!(f, "unsolved"),
+		}
+	}
+}
+
+impl From<u64> for Answer {
+	fn from(number: u64) -> Answer {
+		Answer::Number(number)
+	}
+}
+
+impl From<String> for Answer {
+	fn from(text: String) -> Answer {
+		Answer::Text(text)
+	}
+}
+
+/// A day's puzzle. The input is parsed once, and both parts are solved from the parsed form.
+pub trait Solution: Sized {
+	fn parse(input: String) -> Self;
+
+	fn part_one(&self) -> Answer;
+
+	fn part_two(&self) -> Answer;
+}
+
+/// The answers to a day, along with how long parsing and solving each part took
+pub struct Solved {
+	pub answers: [Answer; 2],
+	pub parse_time: Duration,
+	pub part_times: [Duration; 2],
+}
+
+impl Solved {
+	pub fn solve_time(&self) -> Duration {
+		self.part_times[0] + self.part_times[1]
+	}
+}
+
+pub fn solve_timed<S: Solution>(input: String) -> Solved {
+	let start = Instant::now();
+	let solution = S::parse(input);
+	let parse_time = start.elapsed();
+
+	let start = Instant::now();
+	let part_one = solution.part_one();
+	let part_one_time = start.elapsed();
+
+	let start = Instant::now();
+	let part_two = solution.part_two();
+	let part_two_time = start.elapsed();
+
+	Solved {
+		answers: [part_one, part_two],
+		parse_time,
+		part_times: [part_one_time, part_two_time],
+	}
+}
+
+pub fn solve_part<S: Solution>(input: String, part: u8) -> Answer {
+	let solution = S::parse(input);
+
+	match part {
+		1 => solution.part_one(),
+		2 => solution.part_two(),
+		_ => panic!("Invalid part: {part}"),
+	}
+}
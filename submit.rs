@@ -0,0 +1,122 @@
+use std::{
+	fmt::{self, Display},
+	time::Duration,
+};
+
+use regex::Regex;
+
+/// How adventofcode.com responded to a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+	Correct,
+	TooHigh,
+	TooLow,
+	Wrong,
+	RateLimited(Duration),
+	AlreadySolved,
+}
+
+impl Verdict {
+	pub fn parse(html: &str) -> Option<Verdict> {
+		let text = match Regex::new(r"(?s)<article>(.*)</article>").unwrap().captures(html) {
+			Some(captures) => captures.get(1).unwrap().as_str().to_owned(),
+			None => html.to_owned(),
+		};
+
+		if text.contains("That's the right answer") {
+			Some(Verdict::Correct)
+		} else if text.contains("your answer is too high") {
+			Some(Verdict::TooHigh)
+		} else if text.contains("your answer is too low") {
+			Some(Verdict::TooLow)
+		} else if text.contains("That's not the right answer") {
+			Some(Verdict::Wrong)
+		} else if text.contains("You gave an answer too recently") {
+			Some(Verdict::RateLimited(parse_wait(&text)))
+		} else if text.contains("Did you already complete it") {
+			Some(Verdict::AlreadySolved)
+		} else {
+			None
+		}
+	}
+}
+
+// The wait is phrased like "You have 34s left to wait" or "You have 4m 12s left to wait"
+fn parse_wait(text: &str) -> Duration {
+	let captures = match Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap().captures(text) {
+		Some(captures) => captures,
+		None => return Duration::ZERO,
+	};
+
+	let minutes = captures.get(1).map(|minutes| minutes.as_str().parse::<u64>().unwrap()).unwrap_or(0);
+	let seconds = captures.get(2).unwrap().as_str().parse::<u64>().unwrap();
+
+	Duration::from_secs(minutes * 60 + seconds)
+}
+
+impl Display for Verdict {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Verdict::Correct => write!(f, "That's the right answer!"),
+			Verdict::TooHigh => write!(f, "That's not the right answer; it is too high"),
+			Verdict::TooLow => write!(f, "That's not the right answer; it is too low"),
+			Verdict::Wrong => write!(f, "That's not the right answer"),
+			Verdict::RateLimited(wait) => write!(f, "Answered too recently; wait {}s before trying again", wait.as_secs()),
+			Verdict::AlreadySolved => write!(f, "This part has already been solved"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn reply(text: &str) -> String {
+		format!("<html><body><main><article><p>{text}</p></article></main></body></html>")
+	}
+
+	#[test]
+	fn parses_each_reply() {
+		let replies = [
+			(
+				"That's the right answer! You are one gold star closer to restoring snow operations.",
+				Verdict::Correct,
+			),
+			(
+				"That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data.",
+				Verdict::TooHigh,
+			),
+			(
+				"That's not the right answer; your answer is too low. If you're stuck, make sure you're using the full input data.",
+				Verdict::TooLow,
+			),
+			(
+				"That's not the right answer. If you're stuck, make sure you're using the full input data.",
+				Verdict::Wrong,
+			),
+			(
+				"You don't seem to be solving the right level.  Did you already complete it? [Return to Day 1]",
+				Verdict::AlreadySolved,
+			),
+		];
+
+		for (text, verdict) in replies {
+			assert_eq!(Verdict::parse(&reply(text)), Some(verdict), "{text}");
+		}
+	}
+
+	#[test]
+	fn parses_the_wait_when_rate_limited() {
+		let text = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 12s left to wait.";
+		assert_eq!(Verdict::parse(&reply(text)), Some(Verdict::RateLimited(Duration::from_secs(252))));
+
+		let text =
+			"You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.";
+		assert_eq!(Verdict::parse(&reply(text)), Some(Verdict::RateLimited(Duration::from_secs(34))));
+	}
+
+	#[test]
+	fn ignores_other_pages() {
+		assert_eq!(Verdict::parse(&reply("Something else entirely")), None);
+	}
+}
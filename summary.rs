@@ -0,0 +1,78 @@
+use std::{
+	panic,
+	time::{Duration, Instant},
+};
+
+use crate::{find_day, format_duration, print_table, Day, Solved};
+
+/// The answers and timings of several days, printed as a table with the totals at the bottom
+#[derive(Default)]
+pub struct Summary {
+	rows: Vec<Vec<String>>,
+	parse_total: Duration,
+	solve_total: Duration,
+}
+
+impl Summary {
+	pub fn solved(&mut self, day: &Day, solved: &Solved) {
+		let [part_one, part_two] = &solved.answers;
+
+		self.parse_total += solved.parse_time;
+		self.solve_total += solved.solve_time();
+
+		self.rows.push(vec![
+			day.number.to_string(),
+			day.title.to_owned(),
+			part_one.to_string(),
+			part_two.to_string(),
+			format_duration(solved.parse_time),
+			format_duration(solved.solve_time()),
+		]);
+	}
+
+	/// For a day that couldn't be run, or didn't finish
+	pub fn failed(&mut self, day: &Day, reason: &str) {
+		self.rows.push(vec![day.number.to_string(), day.title.to_owned(), reason.to_owned()]);
+	}
+
+	pub fn print(mut self, wall_time: Duration) {
+		self.rows.push(vec![
+			"total".to_owned(),
+			String::new(),
+			String::new(),
+			String::new(),
+			format_duration(self.parse_total),
+			format_duration(self.solve_total),
+		]);
+
+		print_table(&["day", "title", "part 1", "part 2", "parse", "solve"], &self.rows);
+		println!();
+		println!(
+			"Finished in {} wall-clock, {} summed over every day",
+			format_duration(wall_time),
+			format_duration(self.parse_total + self.solve_total)
+		);
+	}
+}
+
+/// Runs each day on its input, one after another, and prints the summary. This is all a bundled binary does, so it has no
+/// need for the network or the cache.
+pub fn run_bundle(inputs: &[(u64, &str)]) {
+	panic::set_hook(Box::new(|_| {}));
+
+	let start = Instant::now();
+	let mut summary = Summary::default();
+
+	for (number, input) in inputs {
+		let Some(day) = find_day(*number) else {
+			continue;
+		};
+
+		match panic::catch_unwind(|| (day.solve)(input.to_string())) {
+			Ok(solved) => summary.solved(day, &solved),
+			Err(_) => summary.failed(day, "panicked"),
+		}
+	}
+
+	summary.print(start.elapsed());
+}
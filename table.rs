@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+/// Prints rows in aligned columns, with a rule under the headers
+pub fn print_table(headers: &[&str], rows: &[Vec<String>]) {
+	let mut widths = headers.iter().map(|header| header.chars().count()).collect::<Vec<usize>>();
+
+	for row in rows {
+		for (index, cell) in row.iter().enumerate() {
+			widths[index] = widths[index].max(cell.chars().count());
+		}
+	}
+
+	let format_row = |cells: Vec<String>| {
+		cells
+			.iter()
+			.zip(&widths)
+			.map(|(cell, width)| format!("{cell:width$}"))
+			.collect::<Vec<String>>()
+			.join("  ")
+			.trim_end()
+			.to_owned()
+	};
+
+	println!("{}", format_row(headers.iter().map(|header| header.to_string()).collect()));
+	println!("{}", format_row(widths.iter().map(|width| "-".repeat(*width)).collect()));
+
+	for row in rows {
+		println!("{}", format_row(row.clone()));
+	}
+}
+
+pub fn format_duration(duration: Duration) -> String {
+	format!("{duration:.2?}")
+}
@@ -0,0 +1,111 @@
+use std::{
+	path::PathBuf,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tokio::fs::{read_to_string, write};
+
+use crate::{cache::InputsCache, client::FetchError};
+
+/// Spaces out requests to Advent of Code, even across separate invocations, by remembering when the last one was made
+pub struct Throttle {
+	path: PathBuf,
+	interval: Duration,
+	refuse: bool,
+}
+
+impl Throttle {
+	/// When `refuse` is set, a request that comes too soon is refused instead of being delayed
+	pub fn new(cache: &InputsCache, interval: Duration, refuse: bool) -> Throttle {
+		Throttle {
+			path: cache.root().join("last_request"),
+			interval,
+			refuse,
+		}
+	}
+
+	async fn last_request(&self) -> Option<SystemTime> {
+		let millis = read_to_string(&self.path).await.ok()?.trim().parse::<u64>().ok()?;
+
+		Some(UNIX_EPOCH + Duration::from_millis(millis))
+	}
+
+	/// Waits until a request is allowed, and records it as made. A refused request is an error with the remaining wait.
+	pub async fn acquire(&self) -> Result<(), FetchError> {
+		if let Some(last_request) = self.last_request().await {
+			let elapsed = SystemTime::now().duration_since(last_request).unwrap_or_default();
+
+			if elapsed < self.interval {
+				let remaining = self.interval - elapsed;
+
+				if self.refuse {
+					return Err(FetchError::Throttled(remaining));
+				}
+
+				eprintln!("Waiting {:.1}s before making another request...", remaining.as_secs_f64());
+				tokio::time::sleep(remaining).await;
+			}
+		}
+
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+		write(&self.path, now.to_string()).await.map_err(FetchError::Throttle)?;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::account::Account;
+	use std::time::Instant;
+	use tempfile::TempDir;
+
+	async fn throttle(interval: Duration, refuse: bool) -> (TempDir, Throttle) {
+		let directory = tempfile::tempdir().unwrap();
+		let account = Account {
+			name: "default".to_owned(),
+			session: Some("secret".to_owned()),
+		};
+
+		let cache = InputsCache::new(2023, Some(directory.path().to_owned()), &account).await.unwrap();
+
+		(directory, Throttle::new(&cache, interval, refuse))
+	}
+
+	#[tokio::test]
+	async fn records_when_each_request_was_made() {
+		let (_directory, throttle) = throttle(Duration::ZERO, true).await;
+
+		let before = SystemTime::now();
+		throttle.acquire().await.unwrap();
+		let after = SystemTime::now();
+
+		let recorded = throttle.last_request().await.unwrap();
+		assert!(recorded + Duration::from_millis(1) >= before && recorded <= after);
+	}
+
+	#[tokio::test]
+	async fn refuses_a_request_that_comes_too_soon() {
+		let (_directory, throttle) = throttle(Duration::from_secs(60), true).await;
+
+		throttle.acquire().await.unwrap();
+
+		match throttle.acquire().await {
+			Err(FetchError::Throttled(remaining)) => assert!(remaining > Duration::from_secs(59) && remaining <= Duration::from_secs(60)),
+			_ => panic!("Expected the second request to be refused"),
+		}
+	}
+
+	#[tokio::test]
+	async fn delays_a_request_that_comes_too_soon() {
+		let (_directory, throttle) = throttle(Duration::from_millis(300), false).await;
+
+		throttle.acquire().await.unwrap();
+
+		let start = Instant::now();
+		throttle.acquire().await.unwrap();
+
+		assert!(start.elapsed() >= Duration::from_millis(250));
+	}
+}
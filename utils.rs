@@ -4,7 +4,7 @@ pub fn into_lines(input: String) -> Vec<String> {
 	input
 		.split("\n")
 		.map(|line| line.trim().to_owned())
-		.filter(|line| line.len() > 0)
+		.filter(|line| !line.is_empty())
 		.collect()
 }
 
@@ -90,43 +90,14 @@ pub fn nest_vector<T>(vec: Vec<T>, every_n: usize) -> Vec<Vec<T>> {
 }
 
 pub fn safe_sub(a: u64, b: u64) -> u64 {
-	if b > a {
-		0
-	} else {
-		a - b
-	}
-}
-
-pub fn get_factors(number: u64) -> Vec<u64> {
-	(1..number + 1).into_iter().filter(|&x| number % x == 0).collect::<Vec<u64>>()
-}
-
-fn get_common_numbers(mut numbers: Vec<Vec<u64>>) -> Vec<u64> {
-	let driver = numbers.pop().unwrap();
-	let mut common = Vec::new();
-
-	for number in driver {
-		let mut is_missing = false;
-
-		for list in &numbers {
-			if !list.contains(&number) {
-				is_missing = true
-			}
-		}
-
-		if is_missing {
-			common.push(number)
-		}
-	}
-
-	common
+	a.saturating_sub(b)
 }
 
 pub fn least_common_multiple(numbers: Vec<u64>) -> u64 {
-	let mut answer = numbers.get(0).unwrap().clone();
+	let mut answer = *numbers.first().unwrap();
 
 	for index in 1..numbers.len() {
-		let number = numbers.get(index).unwrap().clone();
+		let number = *numbers.get(index).unwrap();
 		answer = (number * answer) / (greatest_common_factor(number, answer));
 	}
 